@@ -8,21 +8,36 @@
 
 mod adapter;
 mod builder;
+mod cache;
+mod circuit_breaker;
+mod config;
 #[cfg(feature = "official_client")]
 mod official_adapter;
 mod settings;
 
 use std::borrow::{Borrow, Cow};
+use std::future::Future;
 
 pub use adapter::*;
 pub use builder::*;
+pub use cache::*;
+pub use circuit_breaker::*;
+pub use config::*;
 pub use settings::*;
 
 use crate::{
-    request::{search::SearchTrait, MultiSearch, ScrollCursor, ScrollSearch},
-    response::{MultiResponse, SearchResults},
+    request::{
+        search::{referenced_fields, Field, ScriptParams, SearchBody, SearchTrait},
+        AsyncSearch, ComponentTemplate, IndexTemplate, MultiSearch, PointInTime, PointInTimeSearch,
+        ScrollCursor, ScrollSearch, SearchTemplate, StoredScript, WriteOptions,
+    },
+    response::{
+        AsyncSearchHandle, ClusterHealth, ExplainResult, FieldCaps, FieldProblem, HealthStatus,
+        IndexStats, MultiResponse, QueryValidation, SearchResults, ServerInfo, ServerVersion,
+        TaskStatus, VersionedDocument, WriteResult,
+    },
 };
-use serde::de::DeserializeOwned;
+use serde::{de::DeserializeOwned, Serialize};
 
 /// The adapter which is used by default for the ClientBuilder
 #[cfg(feature = "official_client")]
@@ -48,6 +63,17 @@ pub enum ClientError {
     /// call to [Client::scroll] or [Client::scroll_search]
     #[error("Missing Scroll ID")]
     MissingScrollId,
+
+    /// A write was rejected because the document has changed
+    /// since it was read, or the external version is too old.
+    /// See [WriteOptions] for the conditions a write can have.
+    #[error("Version Conflict: {0}")]
+    VersionConflict(String),
+
+    /// The [CircuitBreaker] has tripped from too many failed
+    /// requests and the request was not sent to the server
+    #[error("Circuit Breaker Open")]
+    CircuitOpen,
 }
 
 /// Passes requests to the Elasticsearch server it has been configured
@@ -58,6 +84,8 @@ pub enum ClientError {
 pub struct Client<T: ClientAdapter> {
     adapter: T,
     settings: Settings,
+    breaker: Option<CircuitBreaker>,
+    cache: Option<SearchCache>,
 }
 
 #[cfg(feature = "official_client")]
@@ -69,20 +97,164 @@ impl Client<DefaultAdapter> {
 }
 
 impl<T: ClientAdapter> Client<T> {
+    /// Version of the server if it has been detected.  This is
+    /// done by [ClientBuilder::connect], or lazily on the first
+    /// request when the client is built for both `es_7` and `es_8`.
+    pub fn server_version(&self) -> Option<&ServerVersion> {
+        self.adapter.server_version()
+    }
+
+    /// State of the [CircuitBreaker] if the client was built with one
+    pub fn circuit_state(&self) -> Option<CircuitState> {
+        self.breaker.as_ref().map(CircuitBreaker::state)
+    }
+
+    /// Hits and misses of the search [Cache] if the client
+    /// was built with one
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.cache.as_ref().map(SearchCache::stats)
+    }
+
+    /// Fetch the details the server reports about itself,
+    /// such as the cluster name and version.
+    pub async fn info(&self) -> ClientResult<ServerInfo> {
+        let data = self.send(self.adapter.info()).await?;
+        deserialze(data)
+    }
+
+    /// Fetch the health of the cluster
+    pub async fn health(&self) -> ClientResult<ClusterHealth> {
+        let data = self.send(self.adapter.health(None, None)).await?;
+        deserialze(data)
+    }
+
+    /// Waits for the cluster to reach at least the given status,
+    /// giving up after the timeout.  The timeout uses the same
+    /// format as [ScrollSearch::new_with_duration], such as `"30s"`.
+    /// If the status isn't reached the health is still returned
+    /// with [ClusterHealth::timed_out] set.
+    pub async fn wait_for_health(
+        &self,
+        status: HealthStatus,
+        timeout: &str,
+    ) -> ClientResult<ClusterHealth> {
+        let data = self
+            .send(self.adapter.health(Some(status), Some(timeout)))
+            .await?;
+        deserialze(data)
+    }
+
+    /// Fetch document counts, storage sizes, and shard details
+    /// for the index the client is configured with
+    pub async fn index_stats(&self) -> ClientResult<IndexStats> {
+        let data = self.send(self.adapter.index_stats()).await?;
+        deserialze(data)
+    }
+
     /// Fetch a document by ID
     pub async fn get_by_id<D>(&self, id: &str) -> ClientResult<Option<D>>
+    where
+        D: DeserializeOwned,
+    {
+        self.fetch_by_id(id, None).await
+    }
+
+    /// Fetch a document by ID which was written with a routing,
+    /// such as the child of a `join` field routed by it's parent.
+    /// Without the same routing the document may not be found.
+    pub async fn get_by_id_routed<D>(&self, id: &str, routing: &str) -> ClientResult<Option<D>>
+    where
+        D: DeserializeOwned,
+    {
+        self.fetch_by_id(id, Some(routing)).await
+    }
+
+    async fn fetch_by_id<D>(&self, id: &str, routing: Option<&str>) -> ClientResult<Option<D>>
     where
         D: DeserializeOwned,
     {
         use crate::response::single_document::SingleDocument;
 
-        match self.adapter.get_by_id(id).await {
+        match self.send(self.adapter.get_by_id(id, routing)).await {
             Ok(data) => {
                 let single: SingleDocument<D> = deserialze(data)?;
                 Ok(single.doc)
             }
-            Err(AdapterError::NotFound) => Ok(None),
-            Err(other) => Err(ClientError::Adapter(other)),
+            Err(ClientError::Adapter(AdapterError::NotFound)) => Ok(None),
+            Err(other) => Err(other),
+        }
+    }
+
+    /// Fetch a document by ID along with the details needed
+    /// to only write it back if it hasn't changed since
+    pub async fn get_versioned_by_id<D>(
+        &self,
+        id: &str,
+    ) -> ClientResult<Option<VersionedDocument<D>>>
+    where
+        D: DeserializeOwned,
+    {
+        self.fetch_versioned_by_id(id, None).await
+    }
+
+    /// The same as [Client::get_versioned_by_id] for a document
+    /// written with a routing, see [Client::get_by_id_routed]
+    pub async fn get_versioned_by_id_routed<D>(
+        &self,
+        id: &str,
+        routing: &str,
+    ) -> ClientResult<Option<VersionedDocument<D>>>
+    where
+        D: DeserializeOwned,
+    {
+        self.fetch_versioned_by_id(id, Some(routing)).await
+    }
+
+    async fn fetch_versioned_by_id<D>(
+        &self,
+        id: &str,
+        routing: Option<&str>,
+    ) -> ClientResult<Option<VersionedDocument<D>>>
+    where
+        D: DeserializeOwned,
+    {
+        match self.send(self.adapter.get_by_id(id, routing)).await {
+            Ok(data) => Ok(Some(deserialze(data)?)),
+            Err(ClientError::Adapter(AdapterError::NotFound)) => Ok(None),
+            Err(other) => Err(other),
+        }
+    }
+
+    /// Write a document by ID, replacing it if it already exists.
+    /// If the [WriteOptions] conditions aren't met this fails with
+    /// [ClientError::VersionConflict].
+    pub async fn index_document<D>(
+        &self,
+        id: &str,
+        doc: &D,
+        options: &WriteOptions,
+    ) -> ClientResult<WriteResult>
+    where
+        D: Serialize + Sync,
+    {
+        let data = self
+            .send(self.adapter.index_document(id, doc, options))
+            .await?;
+        deserialze(data)
+    }
+
+    /// Delete a document by ID, or `None` if there is no such
+    /// document.  If the [WriteOptions] conditions aren't met
+    /// this fails with [ClientError::VersionConflict].
+    pub async fn delete_document(
+        &self,
+        id: &str,
+        options: &WriteOptions,
+    ) -> ClientResult<Option<WriteResult>> {
+        match self.send(self.adapter.delete_document(id, options)).await {
+            Ok(data) => Ok(Some(deserialze(data)?)),
+            Err(ClientError::Adapter(AdapterError::NotFound)) => Ok(None),
+            Err(other) => Err(other),
         }
     }
 
@@ -94,12 +266,89 @@ impl<T: ClientAdapter> Client<T> {
         let mut body = search.search_body();
         body.apply_defaults(&self.settings);
 
-        match self.adapter.search(&body).await {
-            Ok(data) => Ok(deserialze(data)?),
-            Err(other) => Err(ClientError::Adapter(other)),
+        let cached = self
+            .cache
+            .as_ref()
+            .filter(|_| search.cacheable())
+            .and_then(|cache| Some((cache, self.cache_key(&body)?)));
+
+        if let Some((cache, key)) = &cached {
+            if let Some(data) = cache.get(key) {
+                return deserialze(data);
+            }
+        }
+
+        let data = self.send(self.adapter.search(&body)).await?;
+
+        match cached {
+            Some((cache, key)) => {
+                let results = deserialze(data.clone())?;
+                cache.insert(key, data);
+                Ok(results)
+            }
+            None => deserialze(data),
         }
     }
 
+    /// Explain how the document with the id scores against
+    /// the search, or `None` if there is no such document
+    pub async fn explain(
+        &self,
+        id: &str,
+        search: &impl SearchTrait,
+    ) -> ClientResult<Option<ExplainResult>> {
+        let body = search.search_body().into_query();
+
+        match self.send(self.adapter.explain(id, &body)).await {
+            Ok(data) => Ok(Some(deserialze(data)?)),
+            Err(ClientError::Adapter(AdapterError::NotFound)) => Ok(None),
+            Err(other) => Err(other),
+        }
+    }
+
+    /// Check if the query of the search is valid without running
+    /// it, along with how the server rewrote it into Lucene
+    pub async fn validate(&self, search: &impl SearchTrait) -> ClientResult<QueryValidation> {
+        let body = search.search_body().into_query();
+        let data = self.send(self.adapter.validate(&body)).await?;
+        deserialze(data)
+    }
+
+    /// Fetch the capabilities of the fields across the index
+    /// the client is configured with
+    pub async fn field_caps<I, F>(&self, fields: I) -> ClientResult<FieldCaps>
+    where
+        I: IntoIterator<Item = F>,
+        F: Into<Field>,
+    {
+        let fields: Vec<Field> = fields.into_iter().map(Into::into).collect();
+        let names: Vec<&str> = fields.iter().map(Field::name).collect();
+        let data = self.send(self.adapter.field_caps(&names)).await?;
+        deserialze(data)
+    }
+
+    /// Looks up every field the search references and reports
+    /// any which are unmapped or can't be used the way the search
+    /// uses them.  This is handy as a self-check on startup, as a
+    /// misspelled field otherwise quietly matches nothing.
+    pub async fn check_fields(&self, search: &impl SearchTrait) -> ClientResult<Vec<FieldProblem>> {
+        let mut names: Vec<&str> = referenced_fields(search)
+            .into_iter()
+            .map(|reference| reference.field.name())
+            .collect();
+
+        names.sort_unstable();
+        names.dedup();
+
+        if names.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let data = self.send(self.adapter.field_caps(&names)).await?;
+        let caps: FieldCaps = deserialze(data)?;
+        Ok(caps.problems(search))
+    }
+
     /// Make several searches at once
     pub async fn multi_search<'a, D>(
         &self,
@@ -108,7 +357,7 @@ impl<T: ClientAdapter> Client<T> {
     where
         D: DeserializeOwned,
     {
-        let data = self.adapter.multi_search(search.into()).await?;
+        let data = self.send(self.adapter.multi_search(search.into())).await?;
         Ok(deserialze(data)?)
     }
 
@@ -127,7 +376,7 @@ impl<T: ClientAdapter> Client<T> {
     {
         let duration = "1m";
         let scroll = ScrollSearch::new_with_duration(search, duration);
-        let data = self.adapter.scroll_search(scroll).await?;
+        let data = self.send(self.adapter.scroll_search(scroll)).await?;
         let mut results: SearchResults<D> = deserialze(data)?;
         let cursor = ScrollCursor {
             scroll_id: results
@@ -144,7 +393,7 @@ impl<T: ClientAdapter> Client<T> {
     where
         D: DeserializeOwned,
     {
-        let data = self.adapter.scroll(scroll).await?;
+        let data = self.send(self.adapter.scroll(scroll)).await?;
         let mut results: SearchResults<D> = deserialze(data)?;
         scroll.scroll_id = results
             .take_scroll_id()
@@ -153,6 +402,347 @@ impl<T: ClientAdapter> Client<T> {
     }
 }
 
+impl<T: ClientAdapter> Client<T> {
+    /// Opens a [PointInTime] against the configured index which
+    /// is kept alive for the given duration between searches.
+    /// The format of the duration is the same as it is for
+    /// [ScrollSearch::new_with_duration].
+    pub async fn open_point_in_time<K>(&self, keep_alive: K) -> ClientResult<PointInTime>
+    where
+        K: Into<Cow<'static, str>>,
+    {
+        use crate::response::point_in_time::OpenedPointInTime;
+
+        let keep_alive = keep_alive.into();
+        let data = self
+            .send(self.adapter.open_point_in_time(&keep_alive))
+            .await?;
+        let opened: OpenedPointInTime = deserialze(data)?;
+
+        Ok(PointInTime {
+            id: opened.id,
+            keep_alive,
+        })
+    }
+
+    /// Execute a search against a [PointInTime] which was started
+    /// by [Client::open_point_in_time].  The server may hand back
+    /// a new id for the point in time, which is kept up to date.
+    pub async fn point_in_time_search<D>(
+        &self,
+        pit: &mut PointInTime,
+        search: &impl SearchTrait,
+    ) -> ClientResult<SearchResults<D>>
+    where
+        D: DeserializeOwned,
+    {
+        let data = {
+            let mut pit_search = PointInTimeSearch::new(search, pit);
+            pit_search.search_body.apply_defaults(&self.settings);
+            self.send(self.adapter.point_in_time_search(pit_search))
+                .await?
+        };
+
+        let mut results: SearchResults<D> = deserialze(data)?;
+
+        if let Some(id) = results.take_pit_id() {
+            pit.id = id;
+        }
+
+        Ok(results)
+    }
+
+    /// Releases a [PointInTime] on the server.  A point in time
+    /// which has already expired is considered closed.
+    pub async fn close_point_in_time(&self, pit: PointInTime) -> ClientResult<()> {
+        match self.send(self.adapter.close_point_in_time(&pit.id)).await {
+            Ok(()) | Err(ClientError::Adapter(AdapterError::NotFound)) => Ok(()),
+            Err(other) => Err(other),
+        }
+    }
+}
+
+impl<T: ClientAdapter> Client<T> {
+    /// Starts an [AsyncSearch] which keeps running on the server,
+    /// waiting up to `wait_for_completion_timeout` for it to finish.
+    /// The results are held on the server for `keep_alive` after
+    /// each poll.  Not available on OpenSearch.
+    pub async fn async_search<D, K, W>(
+        &self,
+        search: &impl SearchTrait,
+        keep_alive: K,
+        wait_for_completion_timeout: W,
+    ) -> ClientResult<AsyncSearchHandle<D>>
+    where
+        D: DeserializeOwned,
+        K: Into<Cow<'static, str>>,
+        W: Into<Cow<'static, str>>,
+    {
+        let mut async_search = AsyncSearch::new(search, keep_alive, wait_for_completion_timeout);
+        async_search.search_body.apply_defaults(&self.settings);
+        let keep_alive = async_search.keep_alive.clone();
+
+        let data = self.send(self.adapter.async_search(async_search)).await?;
+        let mut handle: AsyncSearchHandle<D> = deserialze(data)?;
+        handle.keep_alive = keep_alive;
+        Ok(handle)
+    }
+
+    /// Checks on a running async search, waiting up to
+    /// `wait_for_completion_timeout` for it to finish, and
+    /// updates the handle with the latest results.  A handle
+    /// for a search that is finished is left as it is.
+    pub async fn poll_async_search<D>(
+        &self,
+        handle: &mut AsyncSearchHandle<D>,
+        wait_for_completion_timeout: &str,
+    ) -> ClientResult<()>
+    where
+        D: DeserializeOwned,
+    {
+        let Some(id) = handle.id() else {
+            return Ok(());
+        };
+
+        let data = self
+            .send(self.adapter.get_async_search(
+                id,
+                &handle.keep_alive,
+                wait_for_completion_timeout,
+            ))
+            .await?;
+
+        let polled: AsyncSearchHandle<D> = deserialze(data)?;
+        handle.update(polled);
+        Ok(())
+    }
+
+    /// Removes an async search from the server, cancelling it if
+    /// it's still running.  A search which has already expired is
+    /// considered removed.
+    pub async fn delete_async_search<D>(&self, handle: AsyncSearchHandle<D>) -> ClientResult<()> {
+        let Some(id) = handle.id() else {
+            return Ok(());
+        };
+
+        match self.send(self.adapter.delete_async_search(id)).await {
+            Ok(()) | Err(ClientError::Adapter(AdapterError::NotFound)) => Ok(()),
+            Err(other) => Err(other),
+        }
+    }
+
+    /// Stores a script on the server under the id, replacing any
+    /// script already there.  Searches can then reference it with
+    /// [crate::request::search::ScriptSource::Stored].
+    pub async fn put_script(&self, id: &str, script: &StoredScript) -> ClientResult<()> {
+        self.send(self.adapter.put_script(id, script)).await
+    }
+
+    /// Fetch a stored script by id
+    pub async fn get_script(&self, id: &str) -> ClientResult<Option<StoredScript>> {
+        use crate::response::stored_script::FetchedScript;
+
+        match self.send(self.adapter.get_script(id)).await {
+            Ok(data) => {
+                let fetched: FetchedScript = deserialze(data)?;
+                Ok(fetched.script)
+            }
+            Err(ClientError::Adapter(AdapterError::NotFound)) => Ok(None),
+            Err(other) => Err(other),
+        }
+    }
+
+    /// Removes a stored script by id, returning `false`
+    /// if there was no script to remove.
+    pub async fn delete_script(&self, id: &str) -> ClientResult<bool> {
+        match self.send(self.adapter.delete_script(id)).await {
+            Ok(()) => Ok(true),
+            Err(ClientError::Adapter(AdapterError::NotFound)) => Ok(false),
+            Err(other) => Err(other),
+        }
+    }
+
+    /// Execute the mustache search template stored under the
+    /// id, filling it in with the params.
+    pub async fn search_template<D, P>(&self, id: &str, params: P) -> ClientResult<SearchResults<D>>
+    where
+        D: DeserializeOwned,
+        P: Into<ScriptParams>,
+    {
+        let template = SearchTemplate::new(id.to_owned(), params);
+        let data = self.send(self.adapter.search_template(&template)).await?;
+        deserialze(data)
+    }
+
+    /// Stores a composable index template under the name, replacing
+    /// any template already there.  It only applies to indices which
+    /// are created afterwards.
+    pub async fn put_index_template(
+        &self,
+        name: &str,
+        template: &IndexTemplate,
+    ) -> ClientResult<()> {
+        self.send(self.adapter.put_index_template(name, template))
+            .await
+    }
+
+    /// Fetch a composable index template by name
+    pub async fn get_index_template(&self, name: &str) -> ClientResult<Option<IndexTemplate>> {
+        use crate::response::index_template::FetchedIndexTemplates;
+
+        match self.send(self.adapter.get_index_template(name)).await {
+            Ok(data) => {
+                let fetched: FetchedIndexTemplates = deserialze(data)?;
+                Ok(fetched
+                    .index_templates
+                    .into_iter()
+                    .find(|found| found.name == name)
+                    .map(|found| found.index_template))
+            }
+            Err(ClientError::Adapter(AdapterError::NotFound)) => Ok(None),
+            Err(other) => Err(other),
+        }
+    }
+
+    /// Removes a composable index template by name, returning
+    /// `false` if there was no template to remove.
+    pub async fn delete_index_template(&self, name: &str) -> ClientResult<bool> {
+        match self.send(self.adapter.delete_index_template(name)).await {
+            Ok(()) => Ok(true),
+            Err(ClientError::Adapter(AdapterError::NotFound)) => Ok(false),
+            Err(other) => Err(other),
+        }
+    }
+
+    /// Stores a component template under the name, replacing any
+    /// template already there, for index templates to be
+    /// composed of.
+    pub async fn put_component_template(
+        &self,
+        name: &str,
+        template: &ComponentTemplate,
+    ) -> ClientResult<()> {
+        self.send(self.adapter.put_component_template(name, template))
+            .await
+    }
+
+    /// Fetch a component template by name
+    pub async fn get_component_template(
+        &self,
+        name: &str,
+    ) -> ClientResult<Option<ComponentTemplate>> {
+        use crate::response::index_template::FetchedComponentTemplates;
+
+        match self.send(self.adapter.get_component_template(name)).await {
+            Ok(data) => {
+                let fetched: FetchedComponentTemplates = deserialze(data)?;
+                Ok(fetched
+                    .component_templates
+                    .into_iter()
+                    .find(|found| found.name == name)
+                    .map(|found| found.component_template))
+            }
+            Err(ClientError::Adapter(AdapterError::NotFound)) => Ok(None),
+            Err(other) => Err(other),
+        }
+    }
+
+    /// Removes a component template by name, returning `false`
+    /// if there was no template to remove.
+    pub async fn delete_component_template(&self, name: &str) -> ClientResult<bool> {
+        match self
+            .send(self.adapter.delete_component_template(name))
+            .await
+        {
+            Ok(()) => Ok(true),
+            Err(ClientError::Adapter(AdapterError::NotFound)) => Ok(false),
+            Err(other) => Err(other),
+        }
+    }
+
+    /// Fetch the status of a long running task by it's id, which
+    /// looks like `node_id:task_number`
+    pub async fn task(&self, id: &str) -> ClientResult<Option<TaskStatus>> {
+        match self.send(self.adapter.get_task(id, None)).await {
+            Ok(data) => Ok(Some(deserialze(data)?)),
+            Err(ClientError::Adapter(AdapterError::NotFound)) => Ok(None),
+            Err(other) => Err(other),
+        }
+    }
+
+    /// Wait For Task
+    ///
+    /// Polls the task until it has completed and returns how it
+    /// finished.  Each poll waits on the server for as long as the
+    /// poll interval, such as `30s`, before checking again.  A task
+    /// which cannot be found is reported as
+    /// [AdapterError::NotFound].
+    ///
+    pub async fn wait_for_task(&self, id: &str, poll_interval: &str) -> ClientResult<TaskStatus> {
+        loop {
+            let data = self
+                .send(self.adapter.get_task(id, Some(poll_interval)))
+                .await?;
+
+            let status: TaskStatus = deserialze(data)?;
+
+            if status.completed {
+                return Ok(status);
+            }
+        }
+    }
+
+    /// Asks a running task to cancel, returning `false` if there
+    /// was no task to cancel.  Cancelling is not immediate, the
+    /// task stops once it gets to a point where it can.
+    pub async fn cancel_task(&self, id: &str) -> ClientResult<bool> {
+        match self.send(self.adapter.cancel_task(id)).await {
+            Ok(()) => Ok(true),
+            Err(ClientError::Adapter(AdapterError::NotFound)) => Ok(false),
+            Err(other) => Err(other),
+        }
+    }
+}
+
+impl<T: ClientAdapter> Client<T> {
+    /// Sends a request through the [CircuitBreaker], if there is
+    /// one, so that it can fail fast and keep track of failures.
+    async fn send<R, F>(&self, request: F) -> ClientResult<R>
+    where
+        F: Future<Output = Result<R, AdapterError>>,
+    {
+        let result = match &self.breaker {
+            None => request.await,
+            Some(breaker) => {
+                let permit = breaker.try_acquire()?;
+                let result = request.await;
+                permit.record(matches!(
+                    result,
+                    Ok(_) | Err(AdapterError::NotFound | AdapterError::Conflict(_))
+                ));
+                result
+            }
+        };
+
+        result.map_err(|err| match err {
+            AdapterError::Conflict(reason) => ClientError::VersionConflict(reason),
+            other => ClientError::Adapter(other),
+        })
+    }
+
+    /// Identifies a search by where it's sent and its body
+    fn cache_key(&self, body: &SearchBody<'_>) -> Option<String> {
+        let body = serde_json::to_string(body).ok()?;
+
+        #[cfg(feature = "es_7")]
+        if let Some(doc_type) = &self.settings.doc_type {
+            return Some(format!("{}/{doc_type}:{body}", self.settings.index));
+        }
+
+        Some(format!("{}:{body}", self.settings.index))
+    }
+}
+
 fn deserialze<T: DeserializeOwned>(data: String) -> ClientResult<T> {
     serde_json::from_str(&data).map_err(|serde_err| ClientError::Deserialize(serde_err, data))
 }
@@ -1,5 +1,9 @@
 use super::*;
-use crate::request::{MultiSearch, ScrollCursor, ScrollSearch};
+use crate::request::{
+    AsyncSearch, ComponentTemplate, IndexTemplate, MultiSearch, PointInTimeSearch, ScrollCursor,
+    ScrollSearch, SearchTemplate, StoredScript, WriteOptions,
+};
+use crate::response::{HealthStatus, ServerVersion};
 use serde::Serialize;
 
 /// Every error that can be emmited by an adapter
@@ -12,6 +16,10 @@ pub enum AdapterError {
     /// General error to let the caller know document is missing
     #[error("Document not found")]
     NotFound,
+
+    /// A write was rejected because its conditions were not met
+    #[error("Conflict: {0}")]
+    Conflict(String),
 }
 
 /// This trait is to avoid direct calls to a third party crate **AND** be
@@ -29,13 +37,45 @@ pub trait ClientAdapter: private::SealedClientAdapter {
     /// an excape-hatch in case you need to make a low level request
     fn borrow_transport(&self) -> &Self::Transport;
 
-    /// Fetch a document by id, returns the raw body response
-    async fn get_by_id(&self, id: &str) -> Result<String, AdapterError>;
+    /// Fetch a document by id, from the shard for the routing
+    /// if given, returns the raw body response
+    async fn get_by_id(&self, id: &str, routing: Option<&str>) -> Result<String, AdapterError>;
+
+    /// Writes a document by id, returns the raw body response
+    async fn index_document<B: Serialize + Sync>(
+        &self,
+        id: &str,
+        body: &B,
+        options: &WriteOptions,
+    ) -> Result<String, AdapterError>;
+
+    /// Deletes a document by id, returns the raw body response
+    async fn delete_document(
+        &self,
+        id: &str,
+        options: &WriteOptions,
+    ) -> Result<String, AdapterError>;
 
     /// Given a body that can serialize execute a search
     /// against the configured index and possible doc type
     async fn search<B: Serialize + Sync>(&self, body: &B) -> Result<String, AdapterError>;
 
+    /// Given a body with a query explain how the document
+    /// with the id scores against it
+    async fn explain<B: Serialize + Sync>(
+        &self,
+        id: &str,
+        body: &B,
+    ) -> Result<String, AdapterError>;
+
+    /// Given a body with a query check if it is valid against
+    /// the configured index, explaining how it was rewritten
+    async fn validate<B: Serialize + Sync>(&self, body: &B) -> Result<String, AdapterError>;
+
+    /// Fetches the capabilities of fields across the configured
+    /// index, including the indices they are unmapped in
+    async fn field_caps(&self, fields: &[&str]) -> Result<String, AdapterError>;
+
     /// Performs multiple searches at once
     async fn multi_search<'a>(&self, mut searches: MultiSearch<'a>)
         -> Result<String, AdapterError>;
@@ -45,6 +85,103 @@ pub trait ClientAdapter: private::SealedClientAdapter {
 
     /// Continues a scroll search
     async fn scroll(&self, cursor: &ScrollCursor) -> Result<String, AdapterError>;
+
+    /// Starts an async search
+    async fn async_search<'a>(&self, search: AsyncSearch<'a>) -> Result<String, AdapterError>;
+
+    /// Fetches the status and results of an async search
+    async fn get_async_search(
+        &self,
+        id: &str,
+        keep_alive: &str,
+        wait_for_completion_timeout: &str,
+    ) -> Result<String, AdapterError>;
+
+    /// Removes an async search and its results
+    async fn delete_async_search(&self, id: &str) -> Result<(), AdapterError>;
+
+    /// Fetches details the server reports about itself
+    async fn info(&self) -> Result<String, AdapterError>;
+
+    /// Version of the server, if it has been detected
+    fn server_version(&self) -> Option<&ServerVersion>;
+
+    /// Asks the server for it's version if it hasn't been
+    /// detected yet and remembers it for later requests
+    async fn detect_server_version(&self) -> Result<&ServerVersion, AdapterError>;
+
+    /// Uses the version for later requests instead of asking the
+    /// server, unless a version was already detected
+    fn assume_server_version(&self, version: ServerVersion);
+
+    /// Fetches the health of the cluster, optionally waiting
+    /// up until the timeout for it to reach a status
+    async fn health(
+        &self,
+        wait_for: Option<HealthStatus>,
+        timeout: Option<&str>,
+    ) -> Result<String, AdapterError>;
+
+    /// Fetches statistics for the configured index
+    async fn index_stats(&self) -> Result<String, AdapterError>;
+
+    /// Opens a point in time against the configured index
+    async fn open_point_in_time(&self, keep_alive: &str) -> Result<String, AdapterError>;
+
+    /// Performs a search against a point in time
+    async fn point_in_time_search<'a>(
+        &self,
+        search: PointInTimeSearch<'a>,
+    ) -> Result<String, AdapterError>;
+
+    /// Releases the resources held by a point in time
+    async fn close_point_in_time(&self, id: &str) -> Result<(), AdapterError>;
+
+    /// Stores a script on the server under the id
+    async fn put_script(&self, id: &str, script: &StoredScript) -> Result<(), AdapterError>;
+
+    /// Fetches a stored script by id
+    async fn get_script(&self, id: &str) -> Result<String, AdapterError>;
+
+    /// Removes a stored script by id
+    async fn delete_script(&self, id: &str) -> Result<(), AdapterError>;
+
+    /// Executes a search template against the configured
+    /// index and possible doc type
+    async fn search_template(&self, template: &SearchTemplate) -> Result<String, AdapterError>;
+
+    /// Stores a composable index template under the name
+    async fn put_index_template(
+        &self,
+        name: &str,
+        template: &IndexTemplate,
+    ) -> Result<(), AdapterError>;
+
+    /// Fetches a composable index template by name
+    async fn get_index_template(&self, name: &str) -> Result<String, AdapterError>;
+
+    /// Removes a composable index template by name
+    async fn delete_index_template(&self, name: &str) -> Result<(), AdapterError>;
+
+    /// Stores a component template under the name
+    async fn put_component_template(
+        &self,
+        name: &str,
+        template: &ComponentTemplate,
+    ) -> Result<(), AdapterError>;
+
+    /// Fetches a component template by name
+    async fn get_component_template(&self, name: &str) -> Result<String, AdapterError>;
+
+    /// Removes a component template by name
+    async fn delete_component_template(&self, name: &str) -> Result<(), AdapterError>;
+
+    /// Fetches the status of a task, waiting on the server up to
+    /// the time given for it to complete if there is one
+    async fn get_task(&self, id: &str, wait_for: Option<&str>) -> Result<String, AdapterError>;
+
+    /// Asks a running task to cancel
+    async fn cancel_task(&self, id: &str) -> Result<(), AdapterError>;
 }
 
 mod private {
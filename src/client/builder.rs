@@ -1,4 +1,5 @@
 use super::*;
+use std::sync::Arc;
 
 /// Builds a client which can be used to work with Elasticsearch
 #[derive(Debug)]
@@ -8,7 +9,11 @@ pub struct ClientBuilder<T: ClientAdapter> {
     #[cfg(feature = "es_7")]
     doc_type: Option<String>,
     credentials: Option<Credentials>,
+    api_key: Option<ApiKey>,
     default_limit: Option<usize>,
+    circuit_breaker: Option<CircuitBreaker>,
+    cache: Option<SearchCache>,
+    server_version: Option<ServerVersion>,
     adapter: Option<T>,
 }
 
@@ -24,6 +29,16 @@ pub enum BuilderError {
     /// Details which fields are missing or invalid
     #[error("Missing or Bad Data Fields: {0}")]
     BadData(String),
+
+    /// Variant if the server could not be reached
+    /// while connecting
+    #[error("Connecting Failed: {0}")]
+    Connect(#[from] ClientError),
+
+    /// The server is not the kind the client was
+    /// compiled to work with
+    #[error("Incompatible Server: {0}")]
+    IncompatibleServer(String),
 }
 
 impl<T: ClientAdapter> Default for ClientBuilder<T> {
@@ -34,7 +49,11 @@ impl<T: ClientAdapter> Default for ClientBuilder<T> {
             #[cfg(feature = "es_7")]
             doc_type: None,
             credentials: None,
+            api_key: None,
             default_limit: None,
+            circuit_breaker: None,
+            cache: None,
+            server_version: None,
             adapter: None,
         }
     }
@@ -99,6 +118,26 @@ impl<T: ClientAdapter> ClientBuilder<T> {
         self
     }
 
+    /// Set API Key
+    ///
+    /// An alternative to username and password credentials where
+    /// the server has issued an API key.  Both the id and the secret
+    /// of the key are required.  OpenSearch has no API keys, so with
+    /// the `opensearch` feature building fails with [BuilderError::BadData].
+    ///
+    pub fn api_key<S, V>(mut self, id: S, key: V) -> Self
+    where
+        S: Into<String>,
+        V: Into<String>,
+    {
+        let api_key = ApiKey {
+            id: id.into(),
+            key: key.into(),
+        };
+        self.api_key = Some(api_key);
+        self
+    }
+
     /// Default Limit
     ///
     /// Optional limit to apply for any searches which do not specify
@@ -110,6 +149,40 @@ impl<T: ClientAdapter> ClientBuilder<T> {
         self
     }
 
+    /// Circuit Breaker
+    ///
+    /// Stops sending requests to the server for a while once too
+    /// many of them fail, returning [ClientError::CircuitOpen] in
+    /// the meantime.  See [CircuitBreakerConfig] for the knobs.
+    ///
+    pub fn circuit_breaker(mut self, config: CircuitBreakerConfig) -> Self {
+        self.circuit_breaker = Some(CircuitBreaker::new(config));
+        self
+    }
+
+    /// Search Cache
+    ///
+    /// Keeps the responses of searches in a [Cache] so identical
+    /// searches are answered without going to the server.  Searches
+    /// can opt out with [crate::request::search::SearchTrait::cacheable].  How long entries
+    /// live and how many are kept is up to the cache.
+    ///
+    pub fn search_cache<C: Cache + 'static>(mut self, cache: C) -> Self {
+        self.cache = Some(SearchCache::new(Arc::new(cache)));
+        self
+    }
+
+    /// Assume Server Version
+    ///
+    /// Shapes requests for this version of the server instead of
+    /// asking the server for it, such as when the version is known
+    /// ahead of time or a round trip at startup is unwanted.
+    ///
+    pub fn assume_server_version(mut self, version: ServerVersion) -> Self {
+        self.server_version = Some(version);
+        self
+    }
+
     /// Use Adapter
     ///
     /// If you want to supply a specially setup adapter for use that goes
@@ -129,6 +202,13 @@ impl<T: ClientAdapter> ClientBuilder<T> {
     /// can fail by failing to provide certain fields or if the adapter
     /// has problems initializing.
     ///
+    /// Building doesn't talk to the server, as it isn't async.  When
+    /// requests depend on the version of the server, such as dropping
+    /// the document type for Elasticsearch 8, the version is asked for
+    /// by the first request that needs it.  Use [ClientBuilder::connect]
+    /// to ask up front, or [ClientBuilder::assume_server_version] to
+    /// skip asking altogether.
+    ///
     pub fn build(mut self) -> Result<Client<T>, BuilderError> {
         let settings = self.build_settings()?;
 
@@ -138,7 +218,50 @@ impl<T: ClientAdapter> ClientBuilder<T> {
             T::try_new_from(&settings)?
         };
 
-        Ok(Client { adapter, settings })
+        if let Some(version) = self.server_version {
+            adapter.assume_server_version(version);
+        }
+
+        Ok(Client {
+            adapter,
+            settings,
+            breaker: self.circuit_breaker,
+            cache: self.cache,
+        })
+    }
+
+    /// Connect
+    ///
+    /// Builds the client and then asks the server for its version
+    /// to make sure it's the kind of server the client was compiled
+    /// to work with.  The detected version is then available from
+    /// [Client::server_version] and used to shape every request.
+    ///
+    pub async fn connect(self) -> Result<Client<T>, BuilderError> {
+        let client = self.build()?;
+        let version = client
+            .adapter
+            .detect_server_version()
+            .await
+            .map_err(ClientError::Adapter)?;
+
+        #[cfg(feature = "opensearch")]
+        if !version.is_opensearch() {
+            return Err(BuilderError::IncompatibleServer(format!(
+                "expected OpenSearch but found Elasticsearch {}",
+                version.number
+            )));
+        }
+
+        #[cfg(not(feature = "opensearch"))]
+        if version.is_opensearch() {
+            return Err(BuilderError::IncompatibleServer(format!(
+                "found OpenSearch {}, enable the `opensearch` feature",
+                version.number
+            )));
+        }
+
+        Ok(client)
     }
 
     /// Helper method to extract settings from the builder structure.
@@ -154,6 +277,11 @@ impl<T: ClientAdapter> ClientBuilder<T> {
             missing_fields.push("INDEX");
         }
 
+        #[cfg(feature = "opensearch")]
+        if self.api_key.is_some() {
+            missing_fields.push("API_KEY");
+        }
+
         if !missing_fields.is_empty() {
             return Err(BuilderError::BadData(missing_fields.join(",")));
         }
@@ -164,6 +292,7 @@ impl<T: ClientAdapter> ClientBuilder<T> {
             #[cfg(feature = "es_7")]
             doc_type: self.doc_type.take(),
             credentials: self.credentials.take(),
+            api_key: self.api_key.take(),
             default_limit: self.default_limit.take(),
         })
     }
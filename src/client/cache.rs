@@ -0,0 +1,184 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Storage for raw search responses which the client checks before
+/// sending a search to the server.  Keys are made up of the index
+/// and the serialized search body, so identical searches share an
+/// entry.  Implement this to plug in your own store; [LruCache] is
+/// an in-process one that is ready to go.
+pub trait Cache: Send + Sync + Debug {
+    /// the stored response for a key, if there is one and it
+    /// hasn't expired yet
+    fn get(&self, key: &str) -> Option<String>;
+
+    /// store the response for a key
+    fn insert(&self, key: String, response: String);
+}
+
+/// In-process [Cache] which holds onto responses for a fixed time
+/// and evicts the least recently used entry when it's full.
+#[derive(Debug)]
+pub struct LruCache {
+    max_entries: usize,
+    ttl: Duration,
+    inner: Mutex<LruInner>,
+}
+
+#[derive(Debug, Default)]
+struct LruInner {
+    entries: HashMap<String, LruEntry>,
+    recency: BTreeMap<u64, String>,
+    tick: u64,
+}
+
+#[derive(Debug)]
+struct LruEntry {
+    response: String,
+    expires_at: Instant,
+    used_at: u64,
+}
+
+impl LruCache {
+    /// Create a cache holding up to `max_entries` responses for
+    /// as long as the `ttl` each
+    pub fn new(max_entries: usize, ttl: Duration) -> Self {
+        Self {
+            max_entries,
+            ttl,
+            inner: Mutex::default(),
+        }
+    }
+
+    /// how many responses are currently held, including any
+    /// which have expired but haven't been cleared out yet
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// if there are no responses held
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LruInner> {
+        self.inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl LruInner {
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.recency.remove(&entry.used_at);
+        }
+    }
+
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+}
+
+impl Cache for LruCache {
+    fn get(&self, key: &str) -> Option<String> {
+        let mut inner = self.lock();
+        let tick = inner.next_tick();
+        let LruInner {
+            entries, recency, ..
+        } = &mut *inner;
+
+        let entry = entries.get_mut(key)?;
+
+        if entry.expires_at <= Instant::now() {
+            inner.remove(key);
+            return None;
+        }
+
+        let key = recency.remove(&entry.used_at)?;
+        recency.insert(tick, key);
+        entry.used_at = tick;
+
+        Some(entry.response.clone())
+    }
+
+    fn insert(&self, key: String, response: String) {
+        if self.max_entries == 0 {
+            return;
+        }
+
+        let mut inner = self.lock();
+        inner.remove(&key);
+
+        while inner.entries.len() >= self.max_entries {
+            match inner.recency.pop_first() {
+                Some((_, oldest)) => inner.entries.remove(&oldest),
+                None => break,
+            };
+        }
+
+        let used_at = inner.next_tick();
+        let entry = LruEntry {
+            response,
+            expires_at: Instant::now() + self.ttl,
+            used_at,
+        };
+
+        inner.recency.insert(used_at, key.clone());
+        inner.entries.insert(key, entry);
+    }
+}
+
+/// Counts of how searches have fared against the cache
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// searches answered by the cache
+    pub hits: u64,
+
+    /// searches which had to be sent to the server
+    pub misses: u64,
+}
+
+/// Wraps the [Cache] a client was built with, keeping
+/// count of the hits and misses across every clone.
+#[derive(Debug, Clone)]
+pub(crate) struct SearchCache {
+    cache: Arc<dyn Cache>,
+    hits: Arc<AtomicU64>,
+    misses: Arc<AtomicU64>,
+}
+
+impl SearchCache {
+    pub(crate) fn new(cache: Arc<dyn Cache>) -> Self {
+        Self {
+            cache,
+            hits: Arc::default(),
+            misses: Arc::default(),
+        }
+    }
+
+    pub(crate) fn get(&self, key: &str) -> Option<String> {
+        let found = self.cache.get(key);
+
+        match found {
+            Some(_) => self.hits.fetch_add(1, Ordering::Relaxed),
+            None => self.misses.fetch_add(1, Ordering::Relaxed),
+        };
+
+        found
+    }
+
+    pub(crate) fn insert(&self, key: String, response: String) {
+        self.cache.insert(key, response);
+    }
+
+    pub(crate) fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use super::*;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Circuit Breaker Settings
+///
+/// Controls when a [CircuitBreaker] stops letting requests through
+/// to the server and how it goes about letting them through again.
+///
+#[derive(Debug, Clone, Copy)]
+pub struct CircuitBreakerConfig {
+    /// ratio of failed requests, from `0.0` to `1.0`, which opens the circuit
+    pub failure_rate: f64,
+
+    /// how many of the most recent requests the failure rate is measured over
+    pub window_size: usize,
+
+    /// fewest requests in the window before the failure rate is considered
+    pub minimum_requests: usize,
+
+    /// how long the circuit stays open before probing the server again
+    pub open_duration: Duration,
+
+    /// successful probes needed while half open to close the circuit
+    pub half_open_probes: usize,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_rate: 0.5,
+            window_size: 20,
+            minimum_requests: 10,
+            open_duration: Duration::from_secs(30),
+            half_open_probes: 3,
+        }
+    }
+}
+
+/// The states a [CircuitBreaker] moves between
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// requests flow through as normal
+    Closed,
+
+    /// requests fail fast without reaching the server
+    Open,
+
+    /// a limited number of probe requests are let through
+    /// to find out if the server has recovered
+    HalfOpen,
+}
+
+/// Circuit Breaker
+///
+/// Keeps track of how requests to the server are going and fails
+/// fast once too many of them are failing.  Only errors reported
+/// by the adapter, other than a missing document, count as a
+/// failure.  Clones share the same state, so every clone of a
+/// client built with one trips together.
+///
+#[derive(Debug, Clone)]
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    inner: Arc<Mutex<BreakerInner>>,
+}
+
+#[derive(Debug)]
+struct BreakerInner {
+    state: CircuitState,
+    outcomes: VecDeque<bool>,
+    opened_at: Option<Instant>,
+    probes_started: usize,
+    probes_succeeded: usize,
+    generation: u64,
+}
+
+/// Circuit Permit
+///
+/// A request let through by [CircuitBreaker::try_acquire], which
+/// reports how it turned out with [CircuitPermit::record].  A permit
+/// dropped without recording, such as when the request is cancelled,
+/// hands its half open probe back so another request can take it.
+/// Results are only counted in the state they were let through in,
+/// so a slow request can't close a circuit it started before.
+///
+#[derive(Debug)]
+#[must_use = "record how the request turned out"]
+pub struct CircuitPermit {
+    breaker: CircuitBreaker,
+    generation: u64,
+    probe: bool,
+    recorded: bool,
+}
+
+impl CircuitBreaker {
+    /// Create a closed circuit breaker with the given settings
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        let inner = BreakerInner {
+            state: CircuitState::Closed,
+            outcomes: VecDeque::with_capacity(config.window_size),
+            opened_at: None,
+            probes_started: 0,
+            probes_succeeded: 0,
+            generation: 0,
+        };
+
+        Self {
+            config,
+            inner: Arc::new(Mutex::new(inner)),
+        }
+    }
+
+    /// Current state of the circuit.  An open circuit which has
+    /// waited out its open duration reports as half open.
+    pub fn state(&self) -> CircuitState {
+        let mut inner = self.lock();
+        self.refresh(&mut inner);
+        inner.state
+    }
+
+    /// Asks to let a request through, which is refused with
+    /// [ClientError::CircuitOpen] when the circuit is open or
+    /// every half open probe is already taken.
+    pub fn try_acquire(&self) -> ClientResult<CircuitPermit> {
+        let mut inner = self.lock();
+        self.refresh(&mut inner);
+
+        let probe = match inner.state {
+            CircuitState::Closed => false,
+            CircuitState::Open => return Err(ClientError::CircuitOpen),
+            CircuitState::HalfOpen => {
+                if inner.probes_started < self.config.half_open_probes.max(1) {
+                    inner.probes_started += 1;
+                    true
+                } else {
+                    return Err(ClientError::CircuitOpen);
+                }
+            }
+        };
+
+        Ok(CircuitPermit {
+            breaker: self.clone(),
+            generation: inner.generation,
+            probe,
+            recorded: false,
+        })
+    }
+
+    /// counts the outcome of a request let through in the
+    /// generation, which may open or close the circuit
+    fn record(&self, generation: u64, success: bool) {
+        let mut inner = self.lock();
+
+        if inner.generation != generation {
+            return;
+        }
+
+        match inner.state {
+            CircuitState::Open => {}
+            CircuitState::HalfOpen if !success => self.open(&mut inner),
+            CircuitState::HalfOpen => {
+                inner.probes_succeeded += 1;
+
+                if inner.probes_succeeded >= self.config.half_open_probes.max(1) {
+                    inner.state = CircuitState::Closed;
+                    inner.generation += 1;
+                    inner.outcomes.clear();
+                }
+            }
+            CircuitState::Closed => {
+                if inner.outcomes.len() >= self.config.window_size.max(1) {
+                    inner.outcomes.pop_front();
+                }
+                inner.outcomes.push_back(success);
+
+                let total = inner.outcomes.len();
+                let failures = inner.outcomes.iter().filter(|ok| !**ok).count();
+
+                if total >= self.config.minimum_requests
+                    && failures as f64 / total as f64 >= self.config.failure_rate
+                {
+                    self.open(&mut inner);
+                }
+            }
+        }
+    }
+
+    /// hands back the probe of a permit which never recorded
+    fn release_probe(&self, generation: u64) {
+        let mut inner = self.lock();
+
+        if inner.generation == generation && inner.state == CircuitState::HalfOpen {
+            inner.probes_started = inner.probes_started.saturating_sub(1);
+        }
+    }
+
+    fn open(&self, inner: &mut BreakerInner) {
+        inner.state = CircuitState::Open;
+        inner.generation += 1;
+        inner.opened_at = Some(Instant::now());
+        inner.outcomes.clear();
+    }
+
+    /// moves an open circuit to half open once it has waited long enough
+    fn refresh(&self, inner: &mut BreakerInner) {
+        if inner.state != CircuitState::Open {
+            return;
+        }
+
+        let waited = inner
+            .opened_at
+            .is_none_or(|opened| opened.elapsed() >= self.config.open_duration);
+
+        if waited {
+            inner.state = CircuitState::HalfOpen;
+            inner.generation += 1;
+            inner.probes_started = 0;
+            inner.probes_succeeded = 0;
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BreakerInner> {
+        // the state is always left consistent, so a poisoned
+        // lock is still safe to keep using
+        self.inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl CircuitPermit {
+    /// Records how the request turned out, which may open
+    /// or close the circuit
+    pub fn record(mut self, success: bool) {
+        self.recorded = true;
+        self.breaker.record(self.generation, success);
+    }
+}
+
+impl Drop for CircuitPermit {
+    fn drop(&mut self) {
+        if self.probe && !self.recorded {
+            self.breaker.release_probe(self.generation);
+        }
+    }
+}
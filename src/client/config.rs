@@ -0,0 +1,235 @@
+use super::*;
+use serde::Deserialize;
+use std::env::{self, VarError};
+use std::fmt;
+
+/// Client Configuration
+///
+/// Plain data representation of the settings needed to build a
+/// client.  It implements `Deserialize` so it can be loaded from
+/// whatever format your service already keeps configuration in,
+/// such as TOML, YAML or JSON, and then turned into a builder with
+/// [ClientBuilder::from_config].
+///
+/// ```toml
+/// host = "http://localhost:9200"
+/// index = "inventory"
+/// username = "elastic"
+/// password = "secret"
+/// default_limit = 20
+/// ```
+///
+#[derive(Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClientConfig {
+    /// http url to the root of the Elasticsearch server
+    pub host: Option<String>,
+
+    /// index ( or indexes separated by commas ) to target
+    pub index: Option<String>,
+
+    /// legacy document type, if any
+    #[cfg(feature = "es_7")]
+    pub doc_type: Option<String>,
+
+    /// username for basic authentication
+    pub username: Option<String>,
+
+    /// password for basic authentication
+    pub password: Option<String>,
+
+    /// API key in the form of `id:key`
+    pub api_key: Option<String>,
+
+    /// limit applied to searches which do not specify one
+    pub default_limit: Option<usize>,
+}
+
+/// the password and API key are left out so the
+/// config can be logged without leaking them
+impl fmt::Debug for ClientConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("ClientConfig");
+
+        debug.field("host", &self.host).field("index", &self.index);
+
+        #[cfg(feature = "es_7")]
+        debug.field("doc_type", &self.doc_type);
+
+        debug
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| REDACTED))
+            .field("api_key", &self.api_key.as_ref().map(|_| REDACTED))
+            .field("default_limit", &self.default_limit)
+            .finish()
+    }
+}
+
+impl ClientConfig {
+    /// Reads configuration from environment variables where each
+    /// key is the prefix followed by an underscore and the name of
+    /// the setting in upper case.  With a prefix of `ELASTIC_LENS`
+    /// the following are read:
+    ///
+    /// - `ELASTIC_LENS_HOST`
+    /// - `ELASTIC_LENS_INDEX`
+    /// - `ELASTIC_LENS_DOC_TYPE` ( only with `es_7` )
+    /// - `ELASTIC_LENS_USERNAME`
+    /// - `ELASTIC_LENS_PASSWORD`
+    /// - `ELASTIC_LENS_API_KEY`
+    /// - `ELASTIC_LENS_DEFAULT_LIMIT`
+    ///
+    /// Every variable that is missing or invalid is reported back
+    /// by name in a single [BuilderError::BadData].
+    ///
+    pub fn from_env(prefix: &str) -> Result<Self, BuilderError> {
+        let prefix = prefix.trim_end_matches('_');
+        let env_key = |key: &str| format!("{prefix}_{}", key.to_uppercase());
+        let mut bad_keys = vec![];
+
+        let mut read = |key: &str| match env::var(env_key(key)) {
+            Ok(value) if value.is_empty() => None,
+            Ok(value) => Some(value),
+            Err(VarError::NotPresent) => None,
+            Err(VarError::NotUnicode(_)) => {
+                bad_keys.push(env_key(key));
+                None
+            }
+        };
+
+        let host = read("host");
+        let index = read("index");
+        #[cfg(feature = "es_7")]
+        let doc_type = read("doc_type");
+        let username = read("username");
+        let password = read("password");
+        let api_key = read("api_key");
+        let default_limit = read("default_limit");
+
+        let default_limit = match default_limit.map(|limit| limit.parse()) {
+            None => None,
+            Some(Ok(limit)) => Some(limit),
+            Some(Err(_)) => {
+                bad_keys.push(env_key("default_limit"));
+                None
+            }
+        };
+
+        let config = Self {
+            host,
+            index,
+            #[cfg(feature = "es_7")]
+            doc_type,
+            username,
+            password,
+            api_key,
+            default_limit,
+        };
+
+        bad_keys.extend(config.bad_keys(env_key));
+
+        if bad_keys.is_empty() {
+            Ok(config)
+        } else {
+            Err(BuilderError::BadData(bad_keys.join(",")))
+        }
+    }
+
+    /// Names of every key which is either missing or holds a value
+    /// that cannot be used.  The provided function maps the name of
+    /// the setting into the name the caller knows it by.
+    fn bad_keys<F: Fn(&str) -> String>(&self, key_name: F) -> Vec<String> {
+        let mut bad_keys = vec![];
+
+        if self.host.is_none() {
+            bad_keys.push(key_name("host"));
+        }
+
+        if self.index.is_none() {
+            bad_keys.push(key_name("index"));
+        }
+
+        match (&self.username, &self.password) {
+            (Some(_), None) => bad_keys.push(key_name("password")),
+            (None, Some(_)) => bad_keys.push(key_name("username")),
+            _ => {}
+        }
+
+        if let Some(api_key) = &self.api_key {
+            let has_credentials = self.username.is_some() || self.password.is_some();
+
+            // OpenSearch doesn't support API keys at all
+            let unsupported = cfg!(feature = "opensearch");
+
+            if unsupported || has_credentials || split_api_key(api_key).is_none() {
+                bad_keys.push(key_name("api_key"));
+            }
+        }
+
+        bad_keys
+    }
+}
+
+impl<T: ClientAdapter> ClientBuilder<T> {
+    /// From Environment
+    ///
+    /// Creates a builder from environment variables that share the
+    /// given prefix.  See [ClientConfig::from_env] for the variables
+    /// which are read.
+    ///
+    pub fn from_env(prefix: &str) -> Result<Self, BuilderError> {
+        Self::from_config(ClientConfig::from_env(prefix)?)
+    }
+
+    /// From Config
+    ///
+    /// Creates a builder from a [ClientConfig].  Every key that is
+    /// missing or invalid is reported back in a single error, in
+    /// upper case the same as [ClientBuilder::build] reports them.
+    ///
+    pub fn from_config(config: ClientConfig) -> Result<Self, BuilderError> {
+        let bad_keys = config.bad_keys(str::to_uppercase);
+
+        if !bad_keys.is_empty() {
+            return Err(BuilderError::BadData(bad_keys.join(",")));
+        }
+
+        let mut builder = Self::default()
+            .host(config.host.unwrap_or_default())
+            .index(config.index.unwrap_or_default());
+
+        #[cfg(feature = "es_7")]
+        if let Some(doc_type) = config.doc_type {
+            builder = builder.doc_type(doc_type);
+        }
+
+        if let Some(limit) = config.default_limit {
+            builder = builder.default_limit(limit);
+        }
+
+        if let (Some(username), Some(password)) = (config.username, config.password) {
+            builder = builder.credentials(username, password);
+        }
+
+        if let Some((id, key)) = config.api_key.as_deref().and_then(split_api_key) {
+            builder = builder.api_key(id, key);
+        }
+
+        Ok(builder)
+    }
+}
+
+impl<T: ClientAdapter> TryFrom<ClientConfig> for ClientBuilder<T> {
+    type Error = BuilderError;
+
+    fn try_from(value: ClientConfig) -> Result<Self, Self::Error> {
+        Self::from_config(value)
+    }
+}
+
+fn split_api_key(api_key: &str) -> Option<(&str, &str)> {
+    match api_key.split_once(':') {
+        Some((id, key)) if !id.is_empty() && !key.is_empty() => Some((id, key)),
+        _ => None,
+    }
+}
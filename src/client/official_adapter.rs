@@ -1,24 +1,85 @@
 use super::*;
-use crate::request::{MultiSearch, ScrollCursor};
+use crate::request::{
+    AsyncSearch, ComponentTemplate, IndexTemplate, MultiSearch, PointInTimeSearch, ScrollCursor,
+    SearchTemplate, StoredScript, WriteCondition, WriteOptions,
+};
+use crate::response::{HealthStatus, ServerInfo, ServerVersion};
+use std::sync::{Arc, OnceLock};
 
-#[cfg(feature = "es_7")]
+// When built for both `es_7` and `es_8` the 7.x client is used
+// as it can speak to either; the server version then decides
+// the shape of requests that differ between the two.
+#[cfg(any(feature = "es_7", feature = "opensearch"))]
 use elastic_lens_offical_es7::elasticsearch;
 
-#[cfg(feature = "es_8")]
+#[cfg(all(feature = "es_8", not(feature = "es_7")))]
 use elastic_lens_offical_es8::elasticsearch;
 
 use elasticsearch::{
-    http::{request::JsonBody, transport::Transport, Url},
+    auth,
+    http::{
+        headers::HeaderMap,
+        request::JsonBody,
+        transport::{SingleNodeConnectionPool, Transport, TransportBuilder},
+        Method, Url,
+    },
     Elasticsearch,
 };
 use serde::Serialize;
 
 mod util;
 
+/// The index and delete requests are different types which
+/// share the same methods for the conditions of a write
+macro_rules! apply_write_options {
+    ($request:ident, $options:expr) => {{
+        let request = match $options.condition {
+            None => $request,
+            Some(WriteCondition::SeqNo {
+                seq_no,
+                primary_term,
+            }) => $request
+                .if_seq_no(seq_no as i64)
+                .if_primary_term(primary_term as i64),
+            Some(WriteCondition::External(version)) => $request
+                .version(version as i64)
+                .version_type(elasticsearch::params::VersionType::External),
+            Some(WriteCondition::ExternalGte(version)) => $request
+                .version(version as i64)
+                .version_type(elasticsearch::params::VersionType::ExternalGte),
+        };
+
+        match &$options.routing {
+            None => request,
+            Some(routing) => request.routing(routing),
+        }
+    }};
+}
+
 #[derive(Debug, Clone)]
 pub struct ElasticsearchAdapter {
     es_client: Elasticsearch,
     settings: Settings,
+    server_version: Arc<OnceLock<ServerVersion>>,
+}
+
+impl ElasticsearchAdapter {
+    /// The document type to place in request paths.  When built
+    /// for both `es_7` and `es_8` it's only used if the server is
+    /// older than 8, as document types are gone from Elasticsearch 8.
+    #[cfg(feature = "es_7")]
+    async fn doc_type(&self) -> Result<Option<&str>, AdapterError> {
+        let Some(doc_type) = self.settings.doc_type.as_deref() else {
+            return Ok(None);
+        };
+
+        #[cfg(feature = "es_8")]
+        if self.detect_server_version().await?.major() >= Some(8) {
+            return Ok(None);
+        }
+
+        Ok(Some(doc_type))
+    }
 }
 
 #[async_trait::async_trait]
@@ -35,11 +96,19 @@ impl ClientAdapter for ElasticsearchAdapter {
             url.set_password(Some(password)).ok();
         }
 
-        let transport = Transport::single_node(url.as_str())?;
+        let transport = if let Some(ApiKey { id, key }) = &settings.api_key {
+            TransportBuilder::new(SingleNodeConnectionPool::new(url))
+                .auth(auth::Credentials::ApiKey(id.clone(), key.clone()))
+                .build()
+                .map_err(|e| AdapterError::Internal(format!("{:?}", e)))?
+        } else {
+            Transport::single_node(url.as_str())?
+        };
 
         Ok(ElasticsearchAdapter {
             es_client: Elasticsearch::new(transport),
             settings: settings.clone(),
+            server_version: Arc::default(),
         })
     }
 
@@ -47,24 +116,93 @@ impl ClientAdapter for ElasticsearchAdapter {
         &self.es_client
     }
 
-    async fn get_by_id(&self, id: &str) -> Result<String, AdapterError> {
+    async fn get_by_id(&self, id: &str, routing: Option<&str>) -> Result<String, AdapterError> {
         use elasticsearch::GetParts;
 
         #[cfg(feature = "es_7")]
-        let parts = if let Some(doc_type) = &self.settings.doc_type {
+        let parts = if let Some(doc_type) = self.doc_type().await? {
             GetParts::IndexTypeId(&self.settings.index, doc_type, id)
         } else {
             GetParts::IndexId(&self.settings.index, id)
         };
 
-        #[cfg(feature = "es_8")]
+        #[cfg(not(feature = "es_7"))]
         let parts = GetParts::IndexId(&self.settings.index, id);
 
-        let response = self.es_client.get(parts).send().await?;
+        let request = self.es_client.get(parts);
+
+        let response = match routing {
+            None => request,
+            Some(routing) => request.routing(routing),
+        }
+        .send()
+        .await?;
+
+        match response.status_code().as_u16() {
+            200 => Ok(response.text().await?),
+            404 => Err(AdapterError::NotFound),
+            code => Err(AdapterError::Internal(format!(
+                "[{code}]: {}",
+                response.text().await?
+            ))),
+        }
+    }
+
+    async fn index_document<B: Serialize + Sync>(
+        &self,
+        id: &str,
+        body: &B,
+        options: &WriteOptions,
+    ) -> Result<String, AdapterError> {
+        use elasticsearch::IndexParts;
+
+        #[cfg(feature = "es_7")]
+        let parts = if let Some(doc_type) = self.doc_type().await? {
+            IndexParts::IndexTypeId(&self.settings.index, doc_type, id)
+        } else {
+            IndexParts::IndexId(&self.settings.index, id)
+        };
+
+        #[cfg(not(feature = "es_7"))]
+        let parts = IndexParts::IndexId(&self.settings.index, id);
+
+        let request = self.es_client.index(parts).body(body);
+        let response = apply_write_options!(request, options).send().await?;
+
+        match response.status_code().as_u16() {
+            200 | 201 => Ok(response.text().await?),
+            409 => Err(AdapterError::Conflict(response.text().await?)),
+            code => Err(AdapterError::Internal(format!(
+                "[{code}]: {}",
+                response.text().await?
+            ))),
+        }
+    }
+
+    async fn delete_document(
+        &self,
+        id: &str,
+        options: &WriteOptions,
+    ) -> Result<String, AdapterError> {
+        use elasticsearch::DeleteParts;
+
+        #[cfg(feature = "es_7")]
+        let parts = if let Some(doc_type) = self.doc_type().await? {
+            DeleteParts::IndexTypeId(&self.settings.index, doc_type, id)
+        } else {
+            DeleteParts::IndexId(&self.settings.index, id)
+        };
+
+        #[cfg(not(feature = "es_7"))]
+        let parts = DeleteParts::IndexId(&self.settings.index, id);
+
+        let request = self.es_client.delete(parts);
+        let response = apply_write_options!(request, options).send().await?;
 
         match response.status_code().as_u16() {
             200 => Ok(response.text().await?),
             404 => Err(AdapterError::NotFound),
+            409 => Err(AdapterError::Conflict(response.text().await?)),
             code => Err(AdapterError::Internal(format!(
                 "[{code}]: {}",
                 response.text().await?
@@ -81,14 +219,14 @@ impl ClientAdapter for ElasticsearchAdapter {
         let mut doc = [""];
 
         #[cfg(feature = "es_7")]
-        let parts = if let Some(doc_type) = self.settings.doc_type.as_ref() {
-            doc[0] = doc_type.as_str();
+        let parts = if let Some(doc_type) = self.doc_type().await? {
+            doc[0] = doc_type;
             SearchParts::IndexType(&index, &doc)
         } else {
             SearchParts::Index(&index)
         };
 
-        #[cfg(feature = "es_8")]
+        #[cfg(not(feature = "es_7"))]
         let parts = SearchParts::Index(&index);
 
         let response = self
@@ -108,6 +246,96 @@ impl ClientAdapter for ElasticsearchAdapter {
         }
     }
 
+    async fn explain<B: Serialize + Sync>(
+        &self,
+        id: &str,
+        body: &B,
+    ) -> Result<String, AdapterError> {
+        use elasticsearch::ExplainParts;
+
+        let index = self.settings.index.as_str();
+
+        #[cfg(feature = "es_7")]
+        let parts = if let Some(doc_type) = self.doc_type().await? {
+            ExplainParts::IndexTypeId(index, doc_type, id)
+        } else {
+            ExplainParts::IndexId(index, id)
+        };
+
+        #[cfg(not(feature = "es_7"))]
+        let parts = ExplainParts::IndexId(index, id);
+
+        let response = self.es_client.explain(parts).body(body).send().await?;
+
+        match response.status_code().as_u16() {
+            200 => Ok(response.text().await?),
+            404 => Err(AdapterError::NotFound),
+            code => Err(AdapterError::Internal(format!(
+                "[{code}]: {}",
+                response.text().await?
+            ))),
+        }
+    }
+
+    async fn validate<B: Serialize + Sync>(&self, body: &B) -> Result<String, AdapterError> {
+        use elasticsearch::indices::IndicesValidateQueryParts;
+
+        let index = [self.settings.index.as_str()];
+
+        #[cfg(feature = "es_7")]
+        let mut doc = [""];
+
+        #[cfg(feature = "es_7")]
+        let parts = if let Some(doc_type) = self.doc_type().await? {
+            doc[0] = doc_type;
+            IndicesValidateQueryParts::IndexType(&index, &doc)
+        } else {
+            IndicesValidateQueryParts::Index(&index)
+        };
+
+        #[cfg(not(feature = "es_7"))]
+        let parts = IndicesValidateQueryParts::Index(&index);
+
+        let response = self
+            .es_client
+            .indices()
+            .validate_query(parts)
+            .explain(true)
+            .body(body)
+            .send()
+            .await?;
+
+        match response.status_code().as_u16() {
+            200 => Ok(response.text().await?),
+            code => Err(AdapterError::Internal(format!(
+                "[{code}]: {}",
+                response.text().await?
+            ))),
+        }
+    }
+
+    async fn field_caps(&self, fields: &[&str]) -> Result<String, AdapterError> {
+        use elasticsearch::FieldCapsParts;
+
+        let index = [self.settings.index.as_str()];
+
+        let response = self
+            .es_client
+            .field_caps(FieldCapsParts::Index(&index))
+            .fields(fields)
+            .include_unmapped(true)
+            .send()
+            .await?;
+
+        match response.status_code().as_u16() {
+            200 => Ok(response.text().await?),
+            code => Err(AdapterError::Internal(format!(
+                "[{code}]: {}",
+                response.text().await?
+            ))),
+        }
+    }
+
     async fn multi_search<'a>(&self, search: MultiSearch<'a>) -> Result<String, AdapterError> {
         use elasticsearch::MsearchParts;
 
@@ -117,14 +345,14 @@ impl ClientAdapter for ElasticsearchAdapter {
         let mut doc = [""];
 
         #[cfg(feature = "es_7")]
-        let parts = if let Some(doc_type) = self.settings.doc_type.as_ref() {
-            doc[0] = doc_type.as_str();
+        let parts = if let Some(doc_type) = self.doc_type().await? {
+            doc[0] = doc_type;
             MsearchParts::IndexType(&index, &doc)
         } else {
             MsearchParts::Index(&index)
         };
 
-        #[cfg(feature = "es_8")]
+        #[cfg(not(feature = "es_7"))]
         let parts = MsearchParts::Index(&index);
 
         let response = self
@@ -156,14 +384,14 @@ impl ClientAdapter for ElasticsearchAdapter {
         let mut doc = [""];
 
         #[cfg(feature = "es_7")]
-        let parts = if let Some(doc_type) = self.settings.doc_type.as_ref() {
-            doc[0] = doc_type.as_str();
+        let parts = if let Some(doc_type) = self.doc_type().await? {
+            doc[0] = doc_type;
             SearchParts::IndexType(&index, &doc)
         } else {
             SearchParts::Index(&index)
         };
 
-        #[cfg(feature = "es_8")]
+        #[cfg(not(feature = "es_7"))]
         let parts = SearchParts::Index(&index);
 
         let response = self
@@ -201,6 +429,563 @@ impl ClientAdapter for ElasticsearchAdapter {
             ))),
         }
     }
+
+    async fn async_search<'a>(&self, search: AsyncSearch<'a>) -> Result<String, AdapterError> {
+        use elasticsearch::async_search::AsyncSearchSubmitParts;
+
+        let index = [self.settings.index.as_str()];
+
+        let response = self
+            .es_client
+            .async_search()
+            .submit(AsyncSearchSubmitParts::Index(&index))
+            .body(search.search_body)
+            .keep_alive(&search.keep_alive)
+            .wait_for_completion_timeout(&search.wait_for_completion_timeout)
+            .typed_keys(true)
+            .send()
+            .await?;
+
+        match response.status_code().as_u16() {
+            200 => Ok(response.text().await?),
+            code => Err(AdapterError::Internal(format!(
+                "[{code}]: {}",
+                response.text().await?
+            ))),
+        }
+    }
+
+    async fn get_async_search(
+        &self,
+        id: &str,
+        keep_alive: &str,
+        wait_for_completion_timeout: &str,
+    ) -> Result<String, AdapterError> {
+        use elasticsearch::async_search::AsyncSearchGetParts;
+
+        let response = self
+            .es_client
+            .async_search()
+            .get(AsyncSearchGetParts::Id(id))
+            .keep_alive(keep_alive)
+            .wait_for_completion_timeout(wait_for_completion_timeout)
+            .typed_keys(true)
+            .send()
+            .await?;
+
+        match response.status_code().as_u16() {
+            200 => Ok(response.text().await?),
+            404 => Err(AdapterError::NotFound),
+            code => Err(AdapterError::Internal(format!(
+                "[{code}]: {}",
+                response.text().await?
+            ))),
+        }
+    }
+
+    async fn delete_async_search(&self, id: &str) -> Result<(), AdapterError> {
+        use elasticsearch::async_search::AsyncSearchDeleteParts;
+
+        let response = self
+            .es_client
+            .async_search()
+            .delete(AsyncSearchDeleteParts::Id(id))
+            .send()
+            .await?;
+
+        match response.status_code().as_u16() {
+            200 => Ok(()),
+            404 => Err(AdapterError::NotFound),
+            code => Err(AdapterError::Internal(format!(
+                "[{code}]: {}",
+                response.text().await?
+            ))),
+        }
+    }
+
+    async fn info(&self) -> Result<String, AdapterError> {
+        let response = self.es_client.info().send().await?;
+
+        match response.status_code().as_u16() {
+            200 => Ok(response.text().await?),
+            code => Err(AdapterError::Internal(format!(
+                "[{code}]: {}",
+                response.text().await?
+            ))),
+        }
+    }
+
+    fn server_version(&self) -> Option<&ServerVersion> {
+        self.server_version.get()
+    }
+
+    fn assume_server_version(&self, version: ServerVersion) {
+        self.server_version.get_or_init(|| version);
+    }
+
+    async fn detect_server_version(&self) -> Result<&ServerVersion, AdapterError> {
+        if let Some(version) = self.server_version.get() {
+            return Ok(version);
+        }
+
+        let data = self.info().await?;
+        let info: ServerInfo =
+            serde_json::from_str(&data).map_err(|e| AdapterError::Internal(format!("{:?}", e)))?;
+
+        Ok(self.server_version.get_or_init(|| info.version))
+    }
+
+    async fn health(
+        &self,
+        wait_for: Option<HealthStatus>,
+        timeout: Option<&str>,
+    ) -> Result<String, AdapterError> {
+        use elasticsearch::{cluster::ClusterHealthParts, params::WaitForStatus};
+
+        let cluster = self.es_client.cluster();
+        let mut request = cluster.health(ClusterHealthParts::None);
+
+        if let Some(status) = wait_for {
+            request = request.wait_for_status(match status {
+                HealthStatus::Green => WaitForStatus::Green,
+                HealthStatus::Yellow => WaitForStatus::Yellow,
+                HealthStatus::Red => WaitForStatus::Red,
+            });
+        }
+
+        if let Some(timeout) = timeout {
+            request = request.timeout(timeout);
+        }
+
+        let response = request.send().await?;
+
+        // a request that times out waiting for a status
+        // still reports the health of the cluster
+        match response.status_code().as_u16() {
+            200 | 408 => Ok(response.text().await?),
+            code => Err(AdapterError::Internal(format!(
+                "[{code}]: {}",
+                response.text().await?
+            ))),
+        }
+    }
+
+    async fn index_stats(&self) -> Result<String, AdapterError> {
+        use elasticsearch::{indices::IndicesStatsParts, params::Level};
+
+        let index = [self.settings.index.as_str()];
+
+        let response = self
+            .es_client
+            .indices()
+            .stats(IndicesStatsParts::Index(&index))
+            .level(Level::Shards)
+            .send()
+            .await?;
+
+        match response.status_code().as_u16() {
+            200 => Ok(response.text().await?),
+            404 => Err(AdapterError::NotFound),
+            code => Err(AdapterError::Internal(format!(
+                "[{code}]: {}",
+                response.text().await?
+            ))),
+        }
+    }
+
+    async fn open_point_in_time(&self, keep_alive: &str) -> Result<String, AdapterError> {
+        #[cfg(all(feature = "es_7", feature = "es_8"))]
+        {
+            let version = self.detect_server_version().await?;
+
+            if (version.major(), version.minor()) < (Some(7), Some(10)) {
+                return Err(AdapterError::Internal(format!(
+                    "point in time requires Elasticsearch 7.10 or newer, found {}",
+                    version.number
+                )));
+            }
+        }
+
+        #[cfg(not(feature = "opensearch"))]
+        let response = {
+            use elasticsearch::OpenPointInTimeParts;
+
+            let index = [self.settings.index.as_str()];
+
+            self.es_client
+                .open_point_in_time(OpenPointInTimeParts::Index(&index))
+                .keep_alive(keep_alive)
+                .send()
+                .await?
+        };
+
+        // OpenSearch has it's own endpoint for opening a point in time
+        #[cfg(feature = "opensearch")]
+        let response = self
+            .es_client
+            .send(
+                Method::Post,
+                &format!("/{}/_search/point_in_time", self.settings.index),
+                HeaderMap::new(),
+                Some(&[("keep_alive", keep_alive)]),
+                None::<()>,
+                None,
+            )
+            .await?;
+
+        match response.status_code().as_u16() {
+            200 => Ok(response.text().await?),
+            code => Err(AdapterError::Internal(format!(
+                "[{code}]: {}",
+                response.text().await?
+            ))),
+        }
+    }
+
+    async fn point_in_time_search<'a>(
+        &self,
+        search: PointInTimeSearch<'a>,
+    ) -> Result<String, AdapterError> {
+        use elasticsearch::SearchParts;
+
+        // the point in time already knows which index it was
+        // opened against, so it's an error to supply one here
+        let response = self
+            .es_client
+            .search(SearchParts::None)
+            .body(search.search_body)
+            .typed_keys(true)
+            .send()
+            .await?;
+
+        match response.status_code().as_u16() {
+            200 => Ok(response.text().await?),
+            code => Err(AdapterError::Internal(format!(
+                "[{code}]: {}",
+                response.text().await?
+            ))),
+        }
+    }
+
+    async fn close_point_in_time(&self, id: &str) -> Result<(), AdapterError> {
+        #[cfg(not(feature = "opensearch"))]
+        let response = self
+            .es_client
+            .close_point_in_time()
+            .body(serde_json::json!({ "id": id }))
+            .send()
+            .await?;
+
+        #[cfg(feature = "opensearch")]
+        let response = self
+            .es_client
+            .send(
+                Method::Delete,
+                "/_search/point_in_time",
+                HeaderMap::new(),
+                None::<&()>,
+                Some(JsonBody::new(serde_json::json!({ "pit_id": [id] }))),
+                None,
+            )
+            .await?;
+
+        match response.status_code().as_u16() {
+            200 => Ok(()),
+            404 => Err(AdapterError::NotFound),
+            code => Err(AdapterError::Internal(format!(
+                "[{code}]: {}",
+                response.text().await?
+            ))),
+        }
+    }
+
+    async fn put_script(&self, id: &str, script: &StoredScript) -> Result<(), AdapterError> {
+        use elasticsearch::PutScriptParts;
+
+        let response = self
+            .es_client
+            .put_script(PutScriptParts::Id(id))
+            .body(serde_json::json!({ "script": script }))
+            .send()
+            .await?;
+
+        match response.status_code().as_u16() {
+            200 => Ok(()),
+            code => Err(AdapterError::Internal(format!(
+                "[{code}]: {}",
+                response.text().await?
+            ))),
+        }
+    }
+
+    async fn get_script(&self, id: &str) -> Result<String, AdapterError> {
+        use elasticsearch::GetScriptParts;
+
+        let response = self
+            .es_client
+            .get_script(GetScriptParts::Id(id))
+            .send()
+            .await?;
+
+        match response.status_code().as_u16() {
+            200 => Ok(response.text().await?),
+            404 => Err(AdapterError::NotFound),
+            code => Err(AdapterError::Internal(format!(
+                "[{code}]: {}",
+                response.text().await?
+            ))),
+        }
+    }
+
+    async fn delete_script(&self, id: &str) -> Result<(), AdapterError> {
+        use elasticsearch::DeleteScriptParts;
+
+        let response = self
+            .es_client
+            .delete_script(DeleteScriptParts::Id(id))
+            .send()
+            .await?;
+
+        match response.status_code().as_u16() {
+            200 => Ok(()),
+            404 => Err(AdapterError::NotFound),
+            code => Err(AdapterError::Internal(format!(
+                "[{code}]: {}",
+                response.text().await?
+            ))),
+        }
+    }
+
+    async fn search_template(&self, template: &SearchTemplate) -> Result<String, AdapterError> {
+        use elasticsearch::SearchTemplateParts;
+
+        let index = [self.settings.index.as_str()];
+
+        #[cfg(feature = "es_7")]
+        let mut doc = [""];
+
+        #[cfg(feature = "es_7")]
+        let parts = if let Some(doc_type) = self.doc_type().await? {
+            doc[0] = doc_type;
+            SearchTemplateParts::IndexType(&index, &doc)
+        } else {
+            SearchTemplateParts::Index(&index)
+        };
+
+        #[cfg(not(feature = "es_7"))]
+        let parts = SearchTemplateParts::Index(&index);
+
+        let response = self
+            .es_client
+            .search_template(parts)
+            .body(template)
+            .typed_keys(true)
+            .send()
+            .await?;
+
+        match response.status_code().as_u16() {
+            200 => Ok(response.text().await?),
+            code => Err(AdapterError::Internal(format!(
+                "[{code}]: {}",
+                response.text().await?
+            ))),
+        }
+    }
+
+    async fn put_index_template(
+        &self,
+        name: &str,
+        template: &IndexTemplate,
+    ) -> Result<(), AdapterError> {
+        use elasticsearch::indices::IndicesPutIndexTemplateParts;
+
+        let response = self
+            .es_client
+            .indices()
+            .put_index_template(IndicesPutIndexTemplateParts::Name(name))
+            .body(template)
+            .send()
+            .await?;
+
+        match response.status_code().as_u16() {
+            200 => Ok(()),
+            code => Err(AdapterError::Internal(format!(
+                "[{code}]: {}",
+                response.text().await?
+            ))),
+        }
+    }
+
+    async fn get_index_template(&self, name: &str) -> Result<String, AdapterError> {
+        use elasticsearch::indices::IndicesGetIndexTemplateParts;
+
+        // the 7.x client takes a list of names where 8.x takes one
+        #[cfg(any(feature = "es_7", feature = "opensearch"))]
+        let names = [name];
+
+        #[cfg(any(feature = "es_7", feature = "opensearch"))]
+        let parts = IndicesGetIndexTemplateParts::Name(&names);
+
+        #[cfg(all(feature = "es_8", not(feature = "es_7")))]
+        let parts = IndicesGetIndexTemplateParts::Name(name);
+
+        let response = self
+            .es_client
+            .indices()
+            .get_index_template(parts)
+            .send()
+            .await?;
+
+        match response.status_code().as_u16() {
+            200 => Ok(response.text().await?),
+            404 => Err(AdapterError::NotFound),
+            code => Err(AdapterError::Internal(format!(
+                "[{code}]: {}",
+                response.text().await?
+            ))),
+        }
+    }
+
+    async fn delete_index_template(&self, name: &str) -> Result<(), AdapterError> {
+        use elasticsearch::indices::IndicesDeleteIndexTemplateParts;
+
+        let response = self
+            .es_client
+            .indices()
+            .delete_index_template(IndicesDeleteIndexTemplateParts::Name(name))
+            .send()
+            .await?;
+
+        match response.status_code().as_u16() {
+            200 => Ok(()),
+            404 => Err(AdapterError::NotFound),
+            code => Err(AdapterError::Internal(format!(
+                "[{code}]: {}",
+                response.text().await?
+            ))),
+        }
+    }
+
+    async fn put_component_template(
+        &self,
+        name: &str,
+        template: &ComponentTemplate,
+    ) -> Result<(), AdapterError> {
+        use elasticsearch::cluster::ClusterPutComponentTemplateParts;
+
+        let response = self
+            .es_client
+            .cluster()
+            .put_component_template(ClusterPutComponentTemplateParts::Name(name))
+            .body(template)
+            .send()
+            .await?;
+
+        match response.status_code().as_u16() {
+            200 => Ok(()),
+            code => Err(AdapterError::Internal(format!(
+                "[{code}]: {}",
+                response.text().await?
+            ))),
+        }
+    }
+
+    async fn get_component_template(&self, name: &str) -> Result<String, AdapterError> {
+        use elasticsearch::cluster::ClusterGetComponentTemplateParts;
+
+        let names = [name];
+        let response = self
+            .es_client
+            .cluster()
+            .get_component_template(ClusterGetComponentTemplateParts::Name(&names))
+            .send()
+            .await?;
+
+        match response.status_code().as_u16() {
+            200 => Ok(response.text().await?),
+            404 => Err(AdapterError::NotFound),
+            code => Err(AdapterError::Internal(format!(
+                "[{code}]: {}",
+                response.text().await?
+            ))),
+        }
+    }
+
+    async fn delete_component_template(&self, name: &str) -> Result<(), AdapterError> {
+        use elasticsearch::cluster::ClusterDeleteComponentTemplateParts;
+
+        let response = self
+            .es_client
+            .cluster()
+            .delete_component_template(ClusterDeleteComponentTemplateParts::Name(name))
+            .send()
+            .await?;
+
+        match response.status_code().as_u16() {
+            200 => Ok(()),
+            404 => Err(AdapterError::NotFound),
+            code => Err(AdapterError::Internal(format!(
+                "[{code}]: {}",
+                response.text().await?
+            ))),
+        }
+    }
+
+    // the official client only has the tasks api behind it's
+    // experimental flag, so these requests are sent by hand
+    async fn get_task(&self, id: &str, wait_for: Option<&str>) -> Result<String, AdapterError> {
+        let path = format!("/_tasks/{id}");
+
+        let query = wait_for.map(|timeout| [("wait_for_completion", "true"), ("timeout", timeout)]);
+
+        let response = self
+            .es_client
+            .send(
+                Method::Get,
+                &path,
+                HeaderMap::new(),
+                query.as_ref(),
+                None::<()>,
+                None,
+            )
+            .await?;
+
+        match response.status_code().as_u16() {
+            200 => Ok(response.text().await?),
+            404 => Err(AdapterError::NotFound),
+            // running out of time waiting is reported as an error, and
+            // which one differs between versions, so fetch the status
+            // as it is now to find out if the task is still going
+            _ if wait_for.is_some() => self.get_task(id, None).await,
+            code => Err(AdapterError::Internal(format!(
+                "[{code}]: {}",
+                response.text().await?
+            ))),
+        }
+    }
+
+    async fn cancel_task(&self, id: &str) -> Result<(), AdapterError> {
+        let response = self
+            .es_client
+            .send(
+                Method::Post,
+                &format!("/_tasks/{id}/_cancel"),
+                HeaderMap::new(),
+                None::<&()>,
+                None::<()>,
+                None,
+            )
+            .await?;
+
+        match response.status_code().as_u16() {
+            200 => Ok(()),
+            404 => Err(AdapterError::NotFound),
+            code => Err(AdapterError::Internal(format!(
+                "[{code}]: {}",
+                response.text().await?
+            ))),
+        }
+    }
 }
 
 // Convert Error Types from host
@@ -1,3 +1,5 @@
+use std::fmt;
+
 /// Information that drives the client in how it connects
 /// and interactions with an Elasticsearch server
 #[derive(Debug, Clone, Default)]
@@ -7,14 +9,45 @@ pub struct Settings {
     #[cfg(feature = "es_7")]
     pub(super) doc_type: Option<String>,
     pub(super) credentials: Option<Credentials>,
+    pub(super) api_key: Option<ApiKey>,
     pub(crate) default_limit: Option<usize>,
 }
 
 /// Represents the username and password which
 /// are used to connect to the Elasticsearch
 /// server.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Credentials {
     pub(super) username: String,
     pub(super) password: String,
 }
+
+/// Represents the id and secret of an Elasticsearch
+/// API key which is used to connect to the server in
+/// place of a username and password.
+#[derive(Clone)]
+pub struct ApiKey {
+    pub(super) id: String,
+    pub(super) key: String,
+}
+
+/// stands in for secrets when debug printing
+pub(super) const REDACTED: &str = "[redacted]";
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("username", &self.username)
+            .field("password", &REDACTED)
+            .finish()
+    }
+}
+
+impl fmt::Debug for ApiKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ApiKey")
+            .field("id", &self.id)
+            .field("key", &REDACTED)
+            .finish()
+    }
+}
@@ -22,4 +22,16 @@ pub enum Error {
     /// data from the results.
     #[error("{0}")]
     AggResultAccess(#[from] crate::response::AggAccessError),
+
+    /// Any errors from parsing or validating a geo point
+    #[error("{0}")]
+    GeoPoint(#[from] crate::request::search::GeoPointError),
+
+    /// Any errors from creating a distance
+    #[error("{0}")]
+    Distance(#[from] crate::request::search::DistanceError),
+
+    /// Any errors from building a geo shape
+    #[error("{0}")]
+    GeoShape(#[from] crate::request::search::GeoShapeError),
 }
@@ -22,8 +22,8 @@
     )
 )]
 
-#[cfg(all(feature = "es_7", feature = "es_8"))]
-compile_error!("feature \"es_7\" and feature \"es_8\" cannot be enabled at the same time");
+#[cfg(all(feature = "opensearch", any(feature = "es_7", feature = "es_8")))]
+compile_error!("feature \"opensearch\" cannot be enabled with \"es_7\" or \"es_8\"");
 
 pub mod client;
 pub mod request;
@@ -38,9 +38,11 @@ pub use errors::*;
 pub mod prelude {
     pub use crate::client::Client;
     pub use crate::request::search::{
-        by_field, by_script, by_script_score, field, if_all_match, if_any_match, nested,
-        AggregationBuilder, CollapseBuilderTrait, CriteriaBuilder, IntoGeoPoint, Search,
-        SortBuilderTrait, SubAggregationBuilder,
+        boosting, by_field, by_script, by_script_score, by_stored_script, by_stored_script_score,
+        constant_score, dis_max, field, function_score, has_child, has_parent, ids, if_all_match,
+        if_any_match, multi_match, nested, parent_id, query_string, simple_query_string,
+        AggregationBuilder, CollapseBuilderTrait, CriteriaBuilder, IntoGeoPoint,
+        ScoreFunctionBuilder, ScoringBuilder, Search, SortBuilderTrait, SubAggregationBuilder,
     };
     pub use crate::request::MultiSearch;
     pub use crate::response::{Filtered, NumericTerms, Stats, StringTerms};
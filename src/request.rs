@@ -2,8 +2,29 @@
 
 pub mod search;
 
+mod async_search;
+pub use async_search::*;
+
+mod index_settings;
+pub use index_settings::*;
+
+mod index_template;
+pub use index_template::*;
+
 mod multi_search;
 pub use multi_search::*;
 
+mod point_in_time;
+pub use point_in_time::*;
+
 mod scroll_search;
 pub use scroll_search::*;
+
+mod search_template;
+pub use search_template::*;
+
+mod stored_script;
+pub use stored_script::*;
+
+mod write_options;
+pub use write_options::*;
@@ -0,0 +1,38 @@
+use std::borrow::Cow;
+
+use super::search::{SearchBody, SearchTrait};
+
+/// Async Search
+///
+/// Starts a search which keeps running on the server after
+/// the request returns, for searches that take longer than
+/// a request can wait for.  The results are held on the
+/// server for the `keep_alive` duration and can be polled
+/// for with [crate::client::Client::poll_async_search].
+///
+/// Durations use the same format as [super::ScrollSearch].
+///
+#[derive(Debug)]
+pub struct AsyncSearch<'a> {
+    pub(crate) search_body: SearchBody<'a>,
+    pub(crate) keep_alive: Cow<'static, str>,
+    pub(crate) wait_for_completion_timeout: Cow<'static, str>,
+}
+
+impl<'a> AsyncSearch<'a> {
+    /// Creates a search whose results are kept for `keep_alive`
+    /// and which waits up to `wait_for_completion_timeout` for
+    /// the search to finish before returning.
+    pub fn new<T, K, W>(search: &'a T, keep_alive: K, wait_for_completion_timeout: W) -> Self
+    where
+        T: SearchTrait,
+        K: Into<Cow<'static, str>>,
+        W: Into<Cow<'static, str>>,
+    {
+        Self {
+            search_body: search.search_body(),
+            keep_alive: keep_alive.into(),
+            wait_for_completion_timeout: wait_for_completion_timeout.into(),
+        }
+    }
+}
@@ -0,0 +1,313 @@
+use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+
+/// Index Settings
+///
+/// Settings an index is created with.  The common ones have their
+/// own builder methods and anything else can be given with
+/// [IndexSettings::set], such as analysis or lifecycle settings.
+///
+/// ```rust
+/// use elastic_lens::request::IndexSettings;
+///
+/// let settings = IndexSettings::new()
+///     .shards(3)
+///     .replicas(1)
+///     .refresh_every("30s")
+///     .set("codec", "best_compression");
+/// ```
+///
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct IndexSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    number_of_shards: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    number_of_replicas: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    refresh_interval: Option<String>,
+
+    #[serde(flatten)]
+    other: BTreeMap<String, Value>,
+}
+
+impl IndexSettings {
+    /// Settings which leave everything to the server defaults
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// number of primary shards to split the index into
+    pub fn shards(mut self, count: u32) -> Self {
+        self.number_of_shards = Some(count);
+        self
+    }
+
+    /// number of copies kept of each primary shard
+    pub fn replicas(mut self, count: u32) -> Self {
+        self.number_of_replicas = Some(count);
+        self
+    }
+
+    /// how often changes are made visible to search, such as `1s`
+    pub fn refresh_every<S: Into<String>>(mut self, interval: S) -> Self {
+        self.refresh_interval = Some(interval.into());
+        self
+    }
+
+    /// Any Other Setting
+    ///
+    /// Settings are named without the `index.` prefix, such
+    /// as `codec` or `analysis`, and can be nested values.
+    ///
+    pub fn set<K: Into<String>, V: Into<Value>>(mut self, key: K, value: V) -> Self {
+        self.other.insert(key.into(), value.into());
+        self
+    }
+
+    /// number of primary shards, if set
+    pub fn number_of_shards(&self) -> Option<u32> {
+        self.number_of_shards
+    }
+
+    /// number of replica shards, if set
+    pub fn number_of_replicas(&self) -> Option<u32> {
+        self.number_of_replicas
+    }
+
+    /// refresh interval, if set
+    pub fn refresh_interval(&self) -> Option<&str> {
+        self.refresh_interval.as_deref()
+    }
+
+    /// any other setting by name
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.other.get(key)
+    }
+}
+
+// The server hands settings back nested under `index` and
+// with every number as a string, which is accepted here.
+impl<'de> Deserialize<'de> for IndexSettings {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut raw = Map::<String, Value>::deserialize(deserializer)?;
+
+        if let Some(Value::Object(index)) = raw.remove("index") {
+            raw.extend(index);
+        }
+
+        fn number<E: de::Error>(value: Option<Value>) -> Result<Option<u32>, E> {
+            match value {
+                None | Some(Value::Null) => Ok(None),
+                Some(Value::Number(num)) => num
+                    .as_u64()
+                    .and_then(|num| u32::try_from(num).ok())
+                    .map(Some)
+                    .ok_or_else(|| E::custom(format!("invalid count {num}"))),
+                Some(Value::String(text)) => text.parse().map(Some).map_err(E::custom),
+                Some(other) => Err(E::custom(format!("invalid count {other}"))),
+            }
+        }
+
+        let number_of_shards = number(raw.remove("number_of_shards"))?;
+        let number_of_replicas = number(raw.remove("number_of_replicas"))?;
+        let refresh_interval = match raw.remove("refresh_interval") {
+            None | Some(Value::Null) => None,
+            Some(Value::String(interval)) => Some(interval),
+            Some(other) => Some(other.to_string()),
+        };
+
+        Ok(Self {
+            number_of_shards,
+            number_of_replicas,
+            refresh_interval,
+            other: raw.into_iter().collect(),
+        })
+    }
+}
+
+/// Index Mappings
+///
+/// How the fields of the documents in an index are stored
+/// and searched.
+///
+/// ```rust
+/// use elastic_lens::request::{FieldMapping, Mappings};
+///
+/// let mappings = Mappings::new()
+///     .field("name", FieldMapping::text().multi_field("raw", FieldMapping::keyword()))
+///     .field("price", FieldMapping::new("scaled_float").set("scaling_factor", 100))
+///     .field("created_at", FieldMapping::date());
+/// ```
+///
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Mappings {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    properties: BTreeMap<String, FieldMapping>,
+
+    #[serde(flatten)]
+    other: BTreeMap<String, Value>,
+}
+
+impl Mappings {
+    /// Mappings without any fields
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// maps a top level field
+    pub fn field<S: Into<String>>(mut self, name: S, mapping: FieldMapping) -> Self {
+        self.properties.insert(name.into(), mapping);
+        self
+    }
+
+    /// Any Other Mapping Parameter
+    ///
+    /// Such as `dynamic` or `_source`, which apply to
+    /// the index as a whole.
+    ///
+    pub fn set<K: Into<String>, V: Into<Value>>(mut self, key: K, value: V) -> Self {
+        self.other.insert(key.into(), value.into());
+        self
+    }
+
+    /// the mapping of a top level field
+    pub fn property(&self, name: &str) -> Option<&FieldMapping> {
+        self.properties.get(name)
+    }
+
+    /// every top level field and it's mapping
+    pub fn properties(&self) -> &BTreeMap<String, FieldMapping> {
+        &self.properties
+    }
+
+    /// any other mapping parameter by name
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.other.get(key)
+    }
+}
+
+/// Mapping of a single field
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FieldMapping {
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    kind: Option<String>,
+
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    properties: BTreeMap<String, FieldMapping>,
+
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    fields: BTreeMap<String, FieldMapping>,
+
+    #[serde(flatten)]
+    options: BTreeMap<String, Value>,
+}
+
+impl FieldMapping {
+    /// A field of the given type, such as `keyword` or `long`
+    pub fn new<S: Into<String>>(kind: S) -> Self {
+        Self {
+            kind: Some(kind.into()),
+            ..Self::default()
+        }
+    }
+
+    /// An object field, which has no type of it's own and
+    /// is made up of the fields added to it with [FieldMapping::field]
+    pub fn object() -> Self {
+        Self::default()
+    }
+
+    /// exact values used for filtering, sorting and aggregating
+    pub fn keyword() -> Self {
+        Self::new("keyword")
+    }
+
+    /// analyzed full text
+    pub fn text() -> Self {
+        Self::new("text")
+    }
+
+    /// dates and times
+    pub fn date() -> Self {
+        Self::new("date")
+    }
+
+    /// whole numbers
+    pub fn long() -> Self {
+        Self::new("long")
+    }
+
+    /// numbers with a fraction
+    pub fn double() -> Self {
+        Self::new("double")
+    }
+
+    /// true or false
+    pub fn boolean() -> Self {
+        Self::new("boolean")
+    }
+
+    /// latitude and longitude pairs
+    pub fn geo_point() -> Self {
+        Self::new("geo_point")
+    }
+
+    /// objects which are queried independently of each other
+    pub fn nested() -> Self {
+        Self::new("nested")
+    }
+
+    /// stored searches, see [crate::request::search::PercolateFilter]
+    pub fn percolator() -> Self {
+        Self::new("percolator")
+    }
+
+    /// maps a field inside of an object or nested field
+    pub fn field<S: Into<String>>(mut self, name: S, mapping: FieldMapping) -> Self {
+        self.properties.insert(name.into(), mapping);
+        self
+    }
+
+    /// Multi-Field
+    ///
+    /// Indexes the same value a second way under `field.name`,
+    /// such as a keyword alongside text for sorting.
+    ///
+    pub fn multi_field<S: Into<String>>(mut self, name: S, mapping: FieldMapping) -> Self {
+        self.fields.insert(name.into(), mapping);
+        self
+    }
+
+    /// any other mapping parameter such as `analyzer` or `format`
+    pub fn set<K: Into<String>, V: Into<Value>>(mut self, key: K, value: V) -> Self {
+        self.options.insert(key.into(), value.into());
+        self
+    }
+
+    /// type of the field, which objects don't have
+    pub fn kind(&self) -> Option<&str> {
+        self.kind.as_deref()
+    }
+
+    /// the mapping of a field inside of this one
+    pub fn property(&self, name: &str) -> Option<&FieldMapping> {
+        self.properties.get(name)
+    }
+
+    /// the mapping of a multi-field
+    pub fn multi_field_mapping(&self, name: &str) -> Option<&FieldMapping> {
+        self.fields.get(name)
+    }
+
+    /// any other mapping parameter by name
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.options.get(key)
+    }
+}
@@ -0,0 +1,225 @@
+use super::{IndexSettings, Mappings};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Index Template
+///
+/// Defines the settings, mappings and aliases of every new index
+/// with a name matching one of its patterns.  When more than one
+/// template matches, the one with the highest priority is used.
+/// Shared pieces can be kept in a [ComponentTemplate] and pulled
+/// in with [IndexTemplate::composed_of].
+///
+/// ```rust
+/// use elastic_lens::request::{FieldMapping, IndexSettings, IndexTemplate, Mappings};
+///
+/// let template = IndexTemplate::new(["orders-*"])
+///     .priority(200)
+///     .composed_of("base-settings")
+///     .settings(IndexSettings::new().shards(2))
+///     .mappings(Mappings::new().field("total", FieldMapping::double()))
+///     .alias("orders");
+/// ```
+///
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IndexTemplate {
+    index_patterns: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    composed_of: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    priority: Option<u64>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    version: Option<u64>,
+
+    #[serde(default, skip_serializing_if = "TemplateContent::is_empty")]
+    template: TemplateContent,
+}
+
+/// Component Template
+///
+/// A reusable piece of settings, mappings and aliases which
+/// index templates are composed of.
+///
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ComponentTemplate {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    version: Option<u64>,
+
+    #[serde(default)]
+    template: TemplateContent,
+}
+
+/// what both kinds of templates give to the indices
+/// they apply to
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct TemplateContent {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    settings: Option<IndexSettings>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    mappings: Option<Mappings>,
+
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    aliases: BTreeMap<String, Value>,
+}
+
+impl TemplateContent {
+    fn is_empty(&self) -> bool {
+        self.settings.is_none() && self.mappings.is_none() && self.aliases.is_empty()
+    }
+
+    fn alias(&mut self, name: String) {
+        self.aliases
+            .insert(name, Value::Object(serde_json::Map::new()));
+    }
+}
+
+impl IndexTemplate {
+    /// A template for new indices matching any of the
+    /// patterns, such as `logs-*`
+    pub fn new<I, S>(index_patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            index_patterns: index_patterns.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Priority
+    ///
+    /// Decides which template is used when several match the
+    /// same index, the highest priority wins.
+    ///
+    pub fn priority(mut self, priority: u64) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// version number to keep track of changes to the template
+    pub fn version(mut self, version: u64) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Composed Of
+    ///
+    /// Pulls in a component template by name.  Components are
+    /// applied in the order they are added, followed by anything
+    /// given to this template directly.
+    ///
+    pub fn composed_of<S: Into<String>>(mut self, component: S) -> Self {
+        self.composed_of.push(component.into());
+        self
+    }
+
+    /// settings of the indices created from this template
+    pub fn settings(mut self, settings: IndexSettings) -> Self {
+        self.template.settings = Some(settings);
+        self
+    }
+
+    /// mappings of the indices created from this template
+    pub fn mappings(mut self, mappings: Mappings) -> Self {
+        self.template.mappings = Some(mappings);
+        self
+    }
+
+    /// alias each index created from this template is added to
+    pub fn alias<S: Into<String>>(mut self, name: S) -> Self {
+        self.template.alias(name.into());
+        self
+    }
+
+    /// patterns of the index names this template applies to
+    pub fn index_patterns(&self) -> &[String] {
+        &self.index_patterns
+    }
+
+    /// names of the component templates this is composed of
+    pub fn components(&self) -> &[String] {
+        &self.composed_of
+    }
+
+    /// priority, if one was set
+    pub fn get_priority(&self) -> Option<u64> {
+        self.priority
+    }
+
+    /// version, if one was set
+    pub fn get_version(&self) -> Option<u64> {
+        self.version
+    }
+
+    /// settings given directly to this template
+    pub fn get_settings(&self) -> Option<&IndexSettings> {
+        self.template.settings.as_ref()
+    }
+
+    /// mappings given directly to this template
+    pub fn get_mappings(&self) -> Option<&Mappings> {
+        self.template.mappings.as_ref()
+    }
+
+    /// names of the aliases given directly to this template
+    pub fn aliases(&self) -> impl Iterator<Item = &str> {
+        self.template.aliases.keys().map(String::as_str)
+    }
+}
+
+impl ComponentTemplate {
+    /// An empty component template
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// version number to keep track of changes to the template
+    pub fn version(mut self, version: u64) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// settings this component gives to indices
+    pub fn settings(mut self, settings: IndexSettings) -> Self {
+        self.template.settings = Some(settings);
+        self
+    }
+
+    /// mappings this component gives to indices
+    pub fn mappings(mut self, mappings: Mappings) -> Self {
+        self.template.mappings = Some(mappings);
+        self
+    }
+
+    /// alias this component adds indices to
+    pub fn alias<S: Into<String>>(mut self, name: S) -> Self {
+        self.template.alias(name.into());
+        self
+    }
+
+    /// version, if one was set
+    pub fn get_version(&self) -> Option<u64> {
+        self.version
+    }
+
+    /// settings of this component
+    pub fn get_settings(&self) -> Option<&IndexSettings> {
+        self.template.settings.as_ref()
+    }
+
+    /// mappings of this component
+    pub fn get_mappings(&self) -> Option<&Mappings> {
+        self.template.mappings.as_ref()
+    }
+
+    /// names of the aliases of this component
+    pub fn aliases(&self) -> impl Iterator<Item = &str> {
+        self.template.aliases.keys().map(String::as_str)
+    }
+}
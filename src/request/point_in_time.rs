@@ -0,0 +1,58 @@
+use std::borrow::Cow;
+
+use serde::{ser::SerializeMap, Serialize};
+
+use super::search::{SearchBody, SearchTrait};
+
+/// Point In Time
+///
+/// Produced by [crate::client::Client::open_point_in_time]
+/// and used by [crate::client::Client::point_in_time_search]
+/// to run searches against a frozen view of the index.  It
+/// should be handed back to
+/// [crate::client::Client::close_point_in_time] when you are
+/// done with it so the server can free its resources.
+///
+#[derive(Debug, Clone)]
+pub struct PointInTime {
+    pub(crate) id: String,
+    pub(crate) keep_alive: Cow<'static, str>,
+}
+
+impl PointInTime {
+    /// The id the server has issued for this point in time
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl Serialize for PointInTime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("id", &self.id)?;
+        map.serialize_entry("keep_alive", &self.keep_alive)?;
+        map.end()
+    }
+}
+
+/// Point In Time Search
+///
+/// A search which targets a [PointInTime] instead of the
+/// index the client is configured for.
+///
+#[derive(Debug)]
+pub struct PointInTimeSearch<'a> {
+    pub(crate) search_body: SearchBody<'a>,
+}
+
+impl<'a> PointInTimeSearch<'a> {
+    /// Creates a search against the point in time
+    pub fn new<T: SearchTrait>(search: &'a T, pit: &'a PointInTime) -> Self {
+        let mut search_body = search.search_body();
+        search_body.point_in_time(pit);
+        Self { search_body }
+    }
+}
@@ -9,7 +9,10 @@ mod body;
 mod collapsing;
 mod condition;
 mod criterion;
+mod date_math;
 mod field;
+mod field_usage;
+mod geo_shape;
 mod geo_values;
 mod numeric_value;
 mod scalar_value;
@@ -22,7 +25,10 @@ pub use body::*;
 pub use collapsing::*;
 pub use condition::*;
 pub use criterion::*;
+pub use date_math::*;
 pub use field::*;
+pub use field_usage::*;
+pub use geo_shape::*;
 pub use geo_values::*;
 pub use numeric_value::*;
 pub use scalar_value::*;
@@ -35,11 +41,16 @@ pub use target::*;
 pub struct Search {
     positive_criteria: Vec<Criterion>,
     negative_criteria: Vec<Criterion>,
+    scoring: ScoringCriteria,
     sorts: Vec<SortDirective>,
     aggregations: Option<AggCollection>,
     limit: Option<usize>,
     offset: Option<usize>,
     collapse_by: Option<CollapseBy>,
+    track_total_hits: Option<bool>,
+    explain: Option<bool>,
+    profile: Option<bool>,
+    skip_cache: bool,
 }
 
 impl Search {
@@ -52,6 +63,30 @@ impl Search {
     pub fn set_offset(&mut self, offset: usize) {
         self.offset = Some(offset);
     }
+
+    /// whether or not to count every matching document; turning
+    /// this off can speed up searches where the total isn't needed
+    pub fn set_track_total_hits(&mut self, track: bool) {
+        self.track_total_hits = Some(track);
+    }
+
+    /// whether or not each hit should explain how its
+    /// score was computed, see [crate::response::Explanation]
+    pub fn set_explain(&mut self, explain: bool) {
+        self.explain = Some(explain);
+    }
+
+    /// whether or not the response should detail where time was
+    /// spent on each shard, see [crate::response::SearchProfile]
+    pub fn set_profile(&mut self, profile: bool) {
+        self.profile = Some(profile);
+    }
+
+    /// always send this search to the server, even if the
+    /// client has a cache of search responses
+    pub fn skip_cache(&mut self) {
+        self.skip_cache = true;
+    }
 }
 
 impl SearchTrait for Search {
@@ -79,6 +114,14 @@ impl SearchTrait for Search {
         }
     }
 
+    fn scoring_criteria(&self) -> Option<&ScoringCriteria> {
+        if self.scoring.has_data() {
+            Some(&self.scoring)
+        } else {
+            None
+        }
+    }
+
     fn sort_directives(&self) -> Option<&Vec<SortDirective>> {
         if self.sorts.is_empty() {
             None
@@ -94,6 +137,22 @@ impl SearchTrait for Search {
     fn collapse_by(&self) -> Option<&CollapseBy> {
         self.collapse_by.as_ref()
     }
+
+    fn track_total_hits(&self) -> Option<bool> {
+        self.track_total_hits
+    }
+
+    fn explain(&self) -> Option<bool> {
+        self.explain
+    }
+
+    fn profile(&self) -> Option<bool> {
+        self.profile
+    }
+
+    fn cacheable(&self) -> bool {
+        !self.skip_cache
+    }
 }
 
 impl CriteriaBuilder for Search {
@@ -108,6 +167,12 @@ impl CriteriaBuilder for Search {
     }
 }
 
+impl ScoringBuilder for Search {
+    fn scoring_criteria_mut(&mut self) -> &mut ScoringCriteria {
+        &mut self.scoring
+    }
+}
+
 impl AggregationBuilder for Search {
     fn aggregations_mut(&mut self) -> &mut AggCollection {
         self.aggregations.get_or_insert_with(AggCollection::default)
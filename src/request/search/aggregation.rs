@@ -33,6 +33,16 @@ pub enum Aggregation {
     Filter(FilterAggregation),
 }
 
+impl CollectFields for Aggregation {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        match self {
+            Self::Terms(agg) => agg.collect_fields(usage, fields),
+            Self::Stats(agg) => agg.collect_fields(usage, fields),
+            Self::Filter(agg) => agg.collect_fields(usage, fields),
+        }
+    }
+}
+
 impl Serialize for Aggregation {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -43,3 +43,11 @@ impl Serialize for AggCollection {
         self.data.serialize(serializer)
     }
 }
+
+impl CollectFields for AggCollection {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        for agg in self.data.values() {
+            agg.collect_fields(usage, fields);
+        }
+    }
+}
@@ -58,3 +58,11 @@ where
         EmptyBool::default().serialize(serializer)
     }
 }
+
+impl CollectFields for FilterAggregation {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        // the filter narrows documents like a query does
+        self.query.collect_fields(FieldUsage::Query, fields);
+        self.sub_aggs.collect_fields(usage, fields);
+    }
+}
@@ -74,3 +74,9 @@ impl Serialize for StatsAggregation {
 }
 
 impl AggregationData for StatsAggregation {}
+
+impl CollectFields for StatsAggregation {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        self.field.collect_fields(usage, fields);
+    }
+}
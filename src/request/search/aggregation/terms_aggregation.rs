@@ -80,3 +80,10 @@ impl Serialize for TermsAggregation {
         agg.serialize(serializer)
     }
 }
+
+impl CollectFields for TermsAggregation {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        self.field.collect_fields(usage, fields);
+        self.sub_aggs.collect_fields(usage, fields);
+    }
+}
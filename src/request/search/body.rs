@@ -1,4 +1,5 @@
 use crate::client::Settings;
+use crate::request::PointInTime;
 
 use super::*;
 use serde::Serialize;
@@ -12,6 +13,10 @@ impl<'a, S: SearchTrait> From<&'a S> for SearchBody<'a> {
             aggs: value.aggregations(),
             sort: determine_sorts(value),
             collapse: value.collapse_by(),
+            track_total_hits: value.track_total_hits(),
+            explain: value.explain(),
+            profile: value.profile(),
+            pit: None,
         }
     }
 }
@@ -23,8 +28,52 @@ impl<'a> SearchBody<'a> {
             self.size = settings.default_limit;
         }
     }
+
+    /// Targets a point in time instead of an index
+    pub(crate) fn point_in_time(&mut self, pit: &'a PointInTime) {
+        self.pit = Some(pit);
+    }
+
+    /// Only the query of the search, which is all the
+    /// explain and validate endpoints accept
+    pub(crate) fn into_query(self) -> QueryBody<'a> {
+        QueryBody {
+            query: self.into_percolator(),
+        }
+    }
+
+    /// Only the query of the search, as it is stored
+    /// in a `percolator` field
+    pub(crate) fn into_percolator(self) -> PercolatorQuery<'a> {
+        PercolatorQuery { query: self.query }
+    }
 }
 
+/// Body with only the query of a search
+#[derive(Debug, Serialize)]
+#[doc(hidden)]
+pub struct QueryBody<'a> {
+    query: PercolatorQuery<'a>,
+}
+
+/// The query of a search on its own, which serializes into the
+/// value of a `percolator` field.  A search without any criteria
+/// is stored as a `match_all` query.
+#[derive(Debug)]
+pub struct PercolatorQuery<'a> {
+    query: QueryRoot<'a>,
+}
+
+/// A `match_all` query, for where a query is required
+/// but there aren't any criteria to make one from
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct MatchAllQuery {
+    match_all: MatchAllParams,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct MatchAllParams {}
+
 #[derive(Debug)]
 #[doc(hidden)]
 pub enum QueryRoot<'a> {
@@ -66,6 +115,18 @@ pub struct SearchBody<'a> {
 
     #[serde(skip_serializing_if = "SkipNode::not_needed")]
     collapse: Option<&'a CollapseBy>,
+
+    #[serde(skip_serializing_if = "SkipNode::not_needed")]
+    track_total_hits: Option<bool>,
+
+    #[serde(skip_serializing_if = "SkipNode::not_needed")]
+    explain: Option<bool>,
+
+    #[serde(skip_serializing_if = "SkipNode::not_needed")]
+    profile: Option<bool>,
+
+    #[serde(skip_serializing_if = "SkipNode::not_needed")]
+    pit: Option<&'a PointInTime>,
 }
 
 #[derive(Debug, Serialize)]
@@ -75,17 +136,44 @@ pub struct ElasticsearchQuery<'a> {
     pub(super) bool: ElasticsearchBool<'a>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Default, Serialize)]
 #[doc(hidden)]
 pub struct ElasticsearchBool<'a> {
     #[serde(skip_serializing_if = "SkipNode::not_needed")]
     pub(super) filter: Option<&'a Vec<Criterion>>,
 
+    #[serde(skip_serializing_if = "SkipNode::not_needed")]
+    pub(super) must: Option<&'a Vec<Criterion>>,
+
     #[serde(skip_serializing_if = "SkipNode::not_needed")]
     pub(super) must_not: Option<&'a Vec<Criterion>>,
 
     #[serde(skip_serializing_if = "SkipNode::not_needed")]
     pub(super) should: Option<&'a Vec<Criterion>>,
+
+    #[serde(skip_serializing_if = "SkipNode::not_needed")]
+    pub(super) minimum_should_match: Option<&'a ScalarValue>,
+
+    #[serde(skip_serializing_if = "SkipNode::not_needed")]
+    pub(super) boost: Option<f64>,
+}
+
+impl<'a> ElasticsearchBool<'a> {
+    /// bool node of the filter, exclusion, and scoring criteria
+    pub(super) fn new(
+        filter: Option<&'a Vec<Criterion>>,
+        must_not: Option<&'a Vec<Criterion>>,
+        scoring: Option<&'a ScoringCriteria>,
+    ) -> Self {
+        Self {
+            filter,
+            must: scoring.and_then(ScoringCriteria::must),
+            must_not,
+            should: scoring.and_then(ScoringCriteria::should),
+            minimum_should_match: scoring.and_then(ScoringCriteria::minimum_should_match),
+            boost: scoring.and_then(ScoringCriteria::boost),
+        }
+    }
 }
 
 fn determine_root<S: SearchTrait>(search: &S) -> QueryRoot<'_> {
@@ -93,22 +181,22 @@ fn determine_root<S: SearchTrait>(search: &S) -> QueryRoot<'_> {
         QueryRoot::ScriptScore(ScriptScoreRoot {
             script_score: ScriptScoreValues {
                 query: ElasticsearchQuery {
-                    bool: ElasticsearchBool {
-                        filter: search.positive_criteria(),
-                        must_not: search.negative_criteria(),
-                        should: None,
-                    },
+                    bool: ElasticsearchBool::new(
+                        search.positive_criteria(),
+                        search.negative_criteria(),
+                        search.scoring_criteria(),
+                    ),
                 },
                 script: fetch_script(search),
             },
         })
     } else {
         QueryRoot::RootQuery(ElasticsearchQuery {
-            bool: ElasticsearchBool {
-                filter: search.positive_criteria(),
-                must_not: search.negative_criteria(),
-                should: None,
-            },
+            bool: ElasticsearchBool::new(
+                search.positive_criteria(),
+                search.negative_criteria(),
+                search.scoring_criteria(),
+            ),
         })
     }
 }
@@ -161,11 +249,17 @@ impl<'a> SkipNode for ElasticsearchBool<'a> {
             return true;
         }
 
+        if self.must.is_needed() {
+            return true;
+        }
+
         if self.must_not.is_needed() {
             return true;
         }
 
-        false
+        // a bool without clauses still matches everything, so
+        // its boost or minimum can be kept
+        self.minimum_should_match.is_needed() || self.boost.is_needed()
     }
 }
 
@@ -184,6 +278,21 @@ impl<'a> SkipNode for QueryRoot<'a> {
     }
 }
 
+impl<'a> Serialize for PercolatorQuery<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        // percolators, explain and validate require a query,
+        // where a search does not
+        if self.query.is_needed() {
+            self.query.serialize(serializer)
+        } else {
+            MatchAllQuery::default().serialize(serializer)
+        }
+    }
+}
+
 impl<'a> Serialize for QueryRoot<'a> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
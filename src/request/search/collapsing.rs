@@ -51,3 +51,9 @@ pub trait CollapseBuilderTrait {
         }
     }
 }
+
+impl CollectFields for CollapseBy {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        self.field.collect_fields(usage, fields);
+    }
+}
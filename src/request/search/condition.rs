@@ -70,3 +70,83 @@ impl Not for GeoDistanceFilter {
         }
     }
 }
+
+impl Not for PatternFilter {
+    type Output = SearchCondition;
+    fn not(self) -> Self::Output {
+        SearchCondition {
+            criterion: self.into(),
+            tag: Tag::Negative,
+        }
+    }
+}
+
+impl Not for FuzzyFilter {
+    type Output = SearchCondition;
+    fn not(self) -> Self::Output {
+        SearchCondition {
+            criterion: self.into(),
+            tag: Tag::Negative,
+        }
+    }
+}
+
+impl Not for IdsFilter {
+    type Output = SearchCondition;
+    fn not(self) -> Self::Output {
+        SearchCondition {
+            criterion: self.into(),
+            tag: Tag::Negative,
+        }
+    }
+}
+
+impl Not for GeoBoundingBoxFilter {
+    type Output = SearchCondition;
+    fn not(self) -> Self::Output {
+        SearchCondition {
+            criterion: self.into(),
+            tag: Tag::Negative,
+        }
+    }
+}
+
+impl Not for GeoShapeFilter {
+    type Output = SearchCondition;
+    fn not(self) -> Self::Output {
+        SearchCondition {
+            criterion: self.into(),
+            tag: Tag::Negative,
+        }
+    }
+}
+
+impl Not for HasChild {
+    type Output = SearchCondition;
+    fn not(self) -> Self::Output {
+        SearchCondition {
+            criterion: self.into(),
+            tag: Tag::Negative,
+        }
+    }
+}
+
+impl Not for HasParent {
+    type Output = SearchCondition;
+    fn not(self) -> Self::Output {
+        SearchCondition {
+            criterion: self.into(),
+            tag: Tag::Negative,
+        }
+    }
+}
+
+impl Not for ParentIdFilter {
+    type Output = SearchCondition;
+    fn not(self) -> Self::Output {
+        SearchCondition {
+            criterion: self.into(),
+            tag: Tag::Negative,
+        }
+    }
+}
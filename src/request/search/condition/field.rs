@@ -1,4 +1,5 @@
 use super::*;
+use std::borrow::Cow;
 use std::ops::Range;
 
 /// A field to target on the Elasticsearch index
@@ -12,6 +13,34 @@ impl Field {
         SearchCondition::from(TermFilter::single(self.clone(), value))
     }
 
+    /// Select if field has this value, ignoring the case of it
+    pub fn contains_ignoring_case<S: Into<ScalarValue>>(&self, value: S) -> SearchCondition {
+        SearchCondition::from(TermFilter::single_ignoring_case(self.clone(), value))
+    }
+
+    /// Select if a value of the field starts with the prefix
+    pub fn starts_with<P: Into<Cow<'static, str>>>(&self, prefix: P) -> PatternFilter {
+        PatternFilter::new(self.clone(), PatternKind::Prefix, prefix)
+    }
+
+    /// Select if a value of the field matches the wildcard
+    /// pattern, where `?` is any one character and `*` is
+    /// any number of characters
+    pub fn wildcard<P: Into<Cow<'static, str>>>(&self, pattern: P) -> PatternFilter {
+        PatternFilter::new(self.clone(), PatternKind::Wildcard, pattern)
+    }
+
+    /// Select if a value of the field matches the regular expression
+    pub fn regexp<P: Into<Cow<'static, str>>>(&self, pattern: P) -> PatternFilter {
+        PatternFilter::new(self.clone(), PatternKind::Regexp, pattern)
+    }
+
+    /// Select if a value of the field is within a few
+    /// character edits of the value
+    pub fn fuzzy<V: Into<Cow<'static, str>>>(&self, value: V) -> FuzzyFilter {
+        FuzzyFilter::new(self.clone(), value)
+    }
+
     /// Select when values are greater than value
     pub fn greater_than<V: Into<ScalarValue>>(&self, value: V) -> RangeFilterBuilder {
         RangeFilterBuilder::new(self.clone()).greater_than(value)
@@ -50,6 +79,75 @@ impl Field {
         GeoDistanceFilterBuilder::for_field(self.clone()).within(unit)
     }
 
+    /// Starts a new distance filter from a [Distance], failing
+    /// if it's negative, infinite, or not a number
+    pub fn within_distance(
+        &self,
+        distance: Distance,
+    ) -> Result<GeoDistanceFilterBuilder<3>, DistanceError> {
+        GeoDistanceFilterBuilder::for_field(self.clone()).within_distance(distance)
+    }
+
+    /// Select if the analyzed text has any of the terms of the
+    /// text, see [MatchFilter] for more options
+    pub fn matches<Q: Into<Cow<'static, str>>>(&self, text: Q) -> SearchCondition {
+        MatchFilter::new(self.clone(), text).into()
+    }
+
+    /// Select if the text has every term of the phrase in order
+    pub fn matches_phrase<Q: Into<Cow<'static, str>>>(&self, phrase: Q) -> SearchCondition {
+        MatchPhraseFilter::new(self.clone(), phrase).into()
+    }
+
+    /// Select if the text has every term of the phrase in order
+    /// with the last term being the start of a word, which is
+    /// handy for search-as-you-type
+    pub fn matches_phrase_prefix<Q>(&self, phrase: Q) -> SearchCondition
+    where
+        Q: Into<Cow<'static, str>>,
+    {
+        MatchPhraseFilter::prefix(self.clone(), phrase).into()
+    }
+
+    /// Select if the point is inside of the rectangle
+    /// made by the top left and bottom right corners
+    pub fn within_bounding_box<T, B>(&self, top_left: T, bottom_right: B) -> GeoBoundingBoxFilter
+    where
+        T: IntoGeoPoint,
+        B: IntoGeoPoint,
+    {
+        GeoBoundingBoxFilter::new(self.clone(), top_left, bottom_right)
+    }
+
+    /// Select if the point or shape is inside of the polygon
+    /// outlined by the points, see [GeoShapeFilter]
+    pub fn within_polygon<I, P>(&self, points: I) -> GeoShapeFilter
+    where
+        I: IntoIterator<Item = P>,
+        P: IntoGeoPoint,
+    {
+        GeoShapeFilter::new(
+            self.clone(),
+            GeoShapeRelation::Within,
+            GeoShape::polygon(points),
+        )
+    }
+
+    /// Select by how the point or shape relates to the shape
+    pub fn geo_shape(&self, relation: GeoShapeRelation, shape: GeoShape) -> GeoShapeFilter {
+        GeoShapeFilter::new(self.clone(), relation, shape)
+    }
+
+    /// Select the stored searches in this `percolator` field which
+    /// match at least one of the documents.  To percolate documents
+    /// which are not yet serialized see [PercolateFilter::from_documents].
+    pub fn percolates<I>(&self, documents: I) -> SearchCondition
+    where
+        I: IntoIterator<Item = serde_json::Value>,
+    {
+        PercolateFilter::new(self.clone(), documents).into()
+    }
+
     /// Select if the condition has at least one of the values
     /// provided.  This is better than many `contains` fitlers
     /// in an `if_any_match` statement.
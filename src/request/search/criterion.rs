@@ -3,22 +3,58 @@ use serde::Serialize;
 
 mod all_match;
 mod any_match;
+mod boosting;
 mod builder_trait;
+mod constant_score;
+mod dis_max;
 mod exists_filter;
+mod function_score;
+mod fuzzy_filter;
+mod geo_bounding_box_filter;
 mod geo_distance_filter;
+mod geo_shape_filter;
+mod has_child;
+mod has_parent;
+mod ids_filter;
+mod inner_hits;
+mod match_filter;
+mod multi_match;
 mod nested;
 mod not_all;
+mod parent_id_filter;
+mod pattern_filter;
+mod percolate_filter;
+mod query_string;
 mod range_filter;
+mod scoring;
 mod term_filter;
 
 pub use all_match::*;
 pub use any_match::*;
+pub use boosting::*;
 pub use builder_trait::*;
+pub use constant_score::*;
+pub use dis_max::*;
 pub use exists_filter::*;
+pub use function_score::*;
+pub use fuzzy_filter::*;
+pub use geo_bounding_box_filter::*;
 pub use geo_distance_filter::*;
+pub use geo_shape_filter::*;
+pub use has_child::*;
+pub use has_parent::*;
+pub use ids_filter::*;
+pub use inner_hits::*;
+pub use match_filter::*;
+pub use multi_match::*;
 pub use nested::*;
 pub use not_all::*;
+pub use parent_id_filter::*;
+pub use pattern_filter::*;
+pub use percolate_filter::*;
+pub use query_string::*;
 pub use range_filter::*;
+pub use scoring::*;
 pub use term_filter::*;
 
 /// Conditions for a Search
@@ -55,6 +91,60 @@ pub enum Criterion {
 
     /// sub-query designed to filter nested structures
     Nested(NestedFilter),
+
+    /// stored searches which match any of the documents
+    Percolate(PercolateFilter),
+
+    /// full text match of a field
+    Match(MatchFilter),
+
+    /// full text match of a phrase, or phrase prefix, on a field
+    MatchPhrase(MatchPhraseFilter),
+
+    /// full text match across several fields
+    MultiMatch(MultiMatch),
+
+    /// value matches a prefix, wildcard or regular expression
+    Pattern(PatternFilter),
+
+    /// value is within a few edits of a value
+    Fuzzy(FuzzyFilter),
+
+    /// document has one of the ids
+    Ids(IdsFilter),
+
+    /// search in the Lucene query syntax
+    QueryString(QueryString),
+
+    /// search in the simple query string syntax
+    SimpleQueryString(SimpleQueryString),
+
+    /// if the field is inside of a rectangle
+    GeoBoundingBox(GeoBoundingBoxFilter),
+
+    /// how the field relates to a shape
+    GeoShape(GeoShapeFilter),
+
+    /// criteria with functions which adjust the score
+    FunctionScore(FunctionScore),
+
+    /// criteria which lowers the score of some matches
+    Boosting(Boosting),
+
+    /// criteria which all score the same
+    ConstantScore(ConstantScore),
+
+    /// matches if any criterion is true, scored by the best
+    DisMax(DisMax),
+
+    /// parent has a child matching criteria
+    HasChild(HasChild),
+
+    /// child has a parent matching criteria
+    HasParent(HasParent),
+
+    /// children of a parent by id
+    ParentId(ParentIdFilter),
 }
 
 impl Criterion {
@@ -64,11 +154,47 @@ impl Criterion {
             Self::NotAll(not_all) => not_all.has_data(),
             Self::AnyMatch(any) => any.has_data(),
             Self::Nested(query) => query.has_data(),
+            Self::Boosting(query) => query.has_data(),
+            Self::ConstantScore(query) => query.has_data(),
+            Self::DisMax(query) => query.has_data(),
             _ => true,
         }
     }
 }
 
+impl CollectFields for Criterion {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        match self {
+            Self::Contains(filter) => filter.collect_fields(usage, fields),
+            Self::Range(filter) => filter.collect_fields(usage, fields),
+            Self::Exists(filter) => filter.collect_fields(usage, fields),
+            Self::GeoDistance(filter) => filter.collect_fields(usage, fields),
+            Self::AnyMatch(filters) => filters.collect_fields(usage, fields),
+            Self::NotAll(filters) => filters.collect_fields(usage, fields),
+            Self::AllMatch(filters) => filters.collect_fields(usage, fields),
+            Self::Nested(filters) => filters.collect_fields(usage, fields),
+            Self::Percolate(filter) => filter.collect_fields(usage, fields),
+            Self::Match(filter) => filter.collect_fields(usage, fields),
+            Self::MatchPhrase(filter) => filter.collect_fields(usage, fields),
+            Self::MultiMatch(filter) => filter.collect_fields(usage, fields),
+            Self::Pattern(filter) => filter.collect_fields(usage, fields),
+            Self::Fuzzy(filter) => filter.collect_fields(usage, fields),
+            Self::Ids(filter) => filter.collect_fields(usage, fields),
+            Self::QueryString(query) => query.collect_fields(usage, fields),
+            Self::SimpleQueryString(query) => query.collect_fields(usage, fields),
+            Self::GeoBoundingBox(filter) => filter.collect_fields(usage, fields),
+            Self::GeoShape(filter) => filter.collect_fields(usage, fields),
+            Self::FunctionScore(query) => query.collect_fields(usage, fields),
+            Self::Boosting(query) => query.collect_fields(usage, fields),
+            Self::ConstantScore(query) => query.collect_fields(usage, fields),
+            Self::DisMax(query) => query.collect_fields(usage, fields),
+            Self::HasChild(query) => query.collect_fields(usage, fields),
+            Self::HasParent(query) => query.collect_fields(usage, fields),
+            Self::ParentId(filter) => filter.collect_fields(usage, fields),
+        }
+    }
+}
+
 impl Serialize for Criterion {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -83,6 +209,24 @@ impl Serialize for Criterion {
             Self::NotAll(filters) => filters.serialize(serializer),
             Self::AllMatch(filters) => filters.serialize(serializer),
             Self::Nested(filters) => filters.serialize(serializer),
+            Self::Percolate(filter) => filter.serialize(serializer),
+            Self::Match(filter) => filter.serialize(serializer),
+            Self::MatchPhrase(filter) => filter.serialize(serializer),
+            Self::MultiMatch(filter) => filter.serialize(serializer),
+            Self::Pattern(filter) => filter.serialize(serializer),
+            Self::Fuzzy(filter) => filter.serialize(serializer),
+            Self::Ids(filter) => filter.serialize(serializer),
+            Self::QueryString(query) => query.serialize(serializer),
+            Self::SimpleQueryString(query) => query.serialize(serializer),
+            Self::GeoBoundingBox(filter) => filter.serialize(serializer),
+            Self::GeoShape(filter) => filter.serialize(serializer),
+            Self::FunctionScore(query) => query.serialize(serializer),
+            Self::Boosting(query) => query.serialize(serializer),
+            Self::ConstantScore(query) => query.serialize(serializer),
+            Self::DisMax(query) => query.serialize(serializer),
+            Self::HasChild(query) => query.serialize(serializer),
+            Self::HasParent(query) => query.serialize(serializer),
+            Self::ParentId(filter) => filter.serialize(serializer),
         }
     }
 }
@@ -106,4 +250,22 @@ mod private {
     impl SealedCriterion for AnyMatch {}
     impl SealedCriterion for NotAll {}
     impl SealedCriterion for AllMatch {}
+    impl SealedCriterion for PercolateFilter {}
+    impl SealedCriterion for MatchFilter {}
+    impl SealedCriterion for MatchPhraseFilter {}
+    impl SealedCriterion for MultiMatch {}
+    impl SealedCriterion for PatternFilter {}
+    impl SealedCriterion for FuzzyFilter {}
+    impl SealedCriterion for IdsFilter {}
+    impl SealedCriterion for QueryString {}
+    impl SealedCriterion for SimpleQueryString {}
+    impl SealedCriterion for GeoBoundingBoxFilter {}
+    impl SealedCriterion for GeoShapeFilter {}
+    impl SealedCriterion for FunctionScore {}
+    impl SealedCriterion for Boosting {}
+    impl SealedCriterion for ConstantScore {}
+    impl SealedCriterion for DisMax {}
+    impl SealedCriterion for HasChild {}
+    impl SealedCriterion for HasParent {}
+    impl SealedCriterion for ParentIdFilter {}
 }
@@ -13,11 +13,13 @@ use serde::Serialize;
 pub struct AllMatch {
     positive_criteria: Vec<Criterion>,
     negative_criteria: Vec<Criterion>,
+    scoring: ScoringCriteria,
 }
 
 impl AllMatch {
     pub(crate) fn has_data(&self) -> bool {
         !(self.negative_criteria.is_empty() && self.positive_criteria.is_empty())
+            || self.scoring.has_data()
     }
 }
 
@@ -49,17 +51,30 @@ impl Serialize for AllMatch {
         };
 
         let node = ElasticsearchQuery {
-            bool: ElasticsearchBool {
-                filter,
-                must_not,
-                should: None,
-            },
+            bool: ElasticsearchBool::new(filter, must_not, Some(&self.scoring)),
         };
 
         node.serialize(serializer)
     }
 }
 
+/// Serializes as a `match_all` query when there aren't any
+/// criteria, for the places where a query is required
+pub(crate) struct RequiredQuery<'a>(pub(crate) &'a AllMatch);
+
+impl<'a> Serialize for RequiredQuery<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if self.0.has_data() {
+            self.0.serialize(serializer)
+        } else {
+            MatchAllQuery::default().serialize(serializer)
+        }
+    }
+}
+
 impl From<AllMatch> for Criterion {
     fn from(value: AllMatch) -> Self {
         Self::AllMatch(value)
@@ -79,3 +94,17 @@ impl CriteriaBuilder for AllMatch {
         &mut self.negative_criteria
     }
 }
+
+impl ScoringBuilder for AllMatch {
+    fn scoring_criteria_mut(&mut self) -> &mut ScoringCriteria {
+        &mut self.scoring
+    }
+}
+
+impl CollectFields for AllMatch {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        self.positive_criteria.collect_fields(usage, fields);
+        self.negative_criteria.collect_fields(usage, fields);
+        self.scoring.collect_fields(usage, fields);
+    }
+}
@@ -44,9 +44,8 @@ impl Serialize for AnyMatch {
     {
         let node = ElasticsearchQuery {
             bool: ElasticsearchBool {
-                filter: None,
-                must_not: None,
                 should: Some(&self.criteria),
+                ..ElasticsearchBool::default()
             },
         };
 
@@ -73,3 +72,9 @@ impl CriteriaBuilder for AnyMatch {
         unreachable!("should never work with a negative bucket for AnyMatch")
     }
 }
+
+impl CollectFields for AnyMatch {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        self.criteria.collect_fields(usage, fields);
+    }
+}
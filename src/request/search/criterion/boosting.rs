@@ -0,0 +1,130 @@
+use super::*;
+use serde::Serialize;
+
+/// Boosting
+///
+/// Selects with the criteria given to it the same as any other
+/// group, but lowers the score of documents which also match
+/// the criteria given to [Boosting::demote_with] by multiplying
+/// it with the negative boost instead of excluding them.
+///
+/// ```rust
+/// use elastic_lens::prelude::*;
+///
+/// let mut search = Search::default();
+///
+/// search.score_with(boosting(0.5, |query| {
+///     query.score_with(field("title").matches("apple"));
+///     query.demote_with(field("title").matches("pie tart"));
+/// }));
+/// ```
+///
+pub fn boosting<F>(negative_boost: f64, mut func: F) -> Boosting
+where
+    F: FnMut(&mut Boosting),
+{
+    let mut boosting = Boosting {
+        positive: AllMatch::default(),
+        negative: AllMatch::default(),
+        negative_boost,
+    };
+    func(&mut boosting);
+    boosting
+}
+
+/// Criteria where matching documents can be demoted,
+/// built by [boosting]
+#[derive(Debug, Clone)]
+pub struct Boosting {
+    positive: AllMatch,
+    negative: AllMatch,
+    negative_boost: f64,
+}
+
+impl Boosting {
+    /// Demote With
+    ///
+    /// Documents which match the condition have their score
+    /// lowered by the negative boost, but are still selected.
+    ///
+    pub fn demote_with<SC: Into<SearchCondition>>(&mut self, condition: SC) {
+        self.negative.with(condition);
+    }
+
+    /// multiplier between `0` and `1` for demoted documents
+    pub fn negative_boost(&self) -> f64 {
+        self.negative_boost
+    }
+
+    pub(crate) fn has_data(&self) -> bool {
+        self.positive.has_data() || self.negative.has_data()
+    }
+}
+
+impl CriteriaBuilder for Boosting {
+    type Bucket = NormalBucket;
+
+    fn positive_criteria_mut(&mut self) -> &mut Vec<Criterion> {
+        self.positive.positive_criteria_mut()
+    }
+
+    fn negative_criteria_mut(&mut self) -> &mut Vec<Criterion> {
+        self.positive.negative_criteria_mut()
+    }
+}
+
+impl ScoringBuilder for Boosting {
+    fn scoring_criteria_mut(&mut self) -> &mut ScoringCriteria {
+        self.positive.scoring_criteria_mut()
+    }
+}
+
+impl CriterionData for Boosting {}
+
+impl From<Boosting> for Criterion {
+    fn from(value: Boosting) -> Self {
+        Self::Boosting(value)
+    }
+}
+
+/// with nothing to demote only the positive criteria are sent,
+/// and with only criteria to demote everything else is positive
+impl Serialize for Boosting {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(Serialize)]
+        struct Parts<'a> {
+            positive: RequiredQuery<'a>,
+            negative: &'a AllMatch,
+            negative_boost: f64,
+        }
+
+        #[derive(Serialize)]
+        struct BoostingAst<'a> {
+            boosting: Parts<'a>,
+        }
+
+        if !self.negative.has_data() {
+            return self.positive.serialize(serializer);
+        }
+
+        let boosting = BoostingAst {
+            boosting: Parts {
+                positive: RequiredQuery(&self.positive),
+                negative: &self.negative,
+                negative_boost: self.negative_boost,
+            },
+        };
+
+        boosting.serialize(serializer)
+    }
+}
+
+impl CollectFields for Boosting {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        self.positive.collect_fields(usage, fields);
+        self.negative.collect_fields(usage, fields);
+    }
+}
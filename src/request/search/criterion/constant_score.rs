@@ -0,0 +1,102 @@
+use super::*;
+use serde::Serialize;
+
+/// Constant Score
+///
+/// Selects with the criteria given to it and gives every
+/// document it selects the same score, the boost.  Handy when
+/// a filter should count towards the score without how well
+/// it matched mattering.
+///
+/// ```rust
+/// use elastic_lens::prelude::*;
+///
+/// let mut search = Search::default();
+///
+/// search.should_score_with(constant_score(|query| {
+///     query.with(field("tags").contains("sale"));
+///     query.set_boost(1.5);
+/// }));
+/// ```
+///
+pub fn constant_score<F>(mut func: F) -> ConstantScore
+where
+    F: FnMut(&mut ConstantScore),
+{
+    let mut constant_score = ConstantScore::default();
+    func(&mut constant_score);
+    constant_score
+}
+
+/// Criteria which all score the same, built by [constant_score]
+#[derive(Debug, Clone, Default)]
+pub struct ConstantScore {
+    filter: AllMatch,
+    boost: Option<f64>,
+}
+
+impl ConstantScore {
+    /// score given to every document, `1.0` by default
+    pub fn set_boost(&mut self, boost: f64) {
+        self.boost = Some(boost);
+    }
+
+    pub(crate) fn has_data(&self) -> bool {
+        self.filter.has_data()
+    }
+}
+
+impl CriteriaBuilder for ConstantScore {
+    type Bucket = NormalBucket;
+
+    fn positive_criteria_mut(&mut self) -> &mut Vec<Criterion> {
+        self.filter.positive_criteria_mut()
+    }
+
+    fn negative_criteria_mut(&mut self) -> &mut Vec<Criterion> {
+        self.filter.negative_criteria_mut()
+    }
+}
+
+impl CriterionData for ConstantScore {}
+
+impl From<ConstantScore> for Criterion {
+    fn from(value: ConstantScore) -> Self {
+        Self::ConstantScore(value)
+    }
+}
+
+impl Serialize for ConstantScore {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(Serialize)]
+        struct Parts<'a> {
+            filter: &'a AllMatch,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            boost: Option<f64>,
+        }
+
+        #[derive(Serialize)]
+        struct ConstantScoreAst<'a> {
+            constant_score: Parts<'a>,
+        }
+
+        let constant_score = ConstantScoreAst {
+            constant_score: Parts {
+                filter: &self.filter,
+                boost: self.boost,
+            },
+        };
+
+        constant_score.serialize(serializer)
+    }
+}
+
+impl CollectFields for ConstantScore {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        self.filter.collect_fields(usage, fields);
+    }
+}
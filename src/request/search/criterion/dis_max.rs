@@ -0,0 +1,109 @@
+use super::*;
+use serde::Serialize;
+
+/// Dis Max
+///
+/// Selects if any of the criteria given match, like
+/// [if_any_match], but scores by the best matching criterion
+/// instead of adding them all up.  The tie breaker adds in a
+/// fraction of the scores of the other matching criteria.
+///
+/// ```rust
+/// use elastic_lens::prelude::*;
+///
+/// let mut search = Search::default();
+///
+/// search.score_with(dis_max(|query| {
+///     query.with(field("title").matches("brown fox"));
+///     query.with(field("body").matches("brown fox"));
+///     query.set_tie_breaker(0.3);
+/// }));
+/// ```
+///
+pub fn dis_max<F>(mut func: F) -> DisMax
+where
+    F: FnMut(&mut DisMax),
+{
+    let mut dis_max = DisMax::default();
+    func(&mut dis_max);
+    dis_max
+}
+
+/// A grouped set of criteria scored by the best
+/// match, built by [dis_max]
+#[derive(Debug, Clone, Default)]
+pub struct DisMax {
+    queries: Vec<Criterion>,
+    tie_breaker: Option<f64>,
+}
+
+impl DisMax {
+    /// Tie Breaker
+    ///
+    /// Between `0` and `1`, how much the scores of the other
+    /// matching criteria count.  At `0`, the default, only the
+    /// best one counts.
+    ///
+    pub fn set_tie_breaker(&mut self, tie_breaker: f64) {
+        self.tie_breaker = Some(tie_breaker);
+    }
+
+    pub(crate) fn has_data(&self) -> bool {
+        !self.queries.is_empty()
+    }
+}
+
+impl CriteriaBuilder for DisMax {
+    type Bucket = GroupedOrBucket;
+
+    fn positive_criteria_mut(&mut self) -> &mut Vec<Criterion> {
+        &mut self.queries
+    }
+
+    fn negative_criteria_mut(&mut self) -> &mut Vec<Criterion> {
+        unreachable!("should never work with a negative bucket for DisMax")
+    }
+}
+
+impl CriterionData for DisMax {}
+
+impl From<DisMax> for Criterion {
+    fn from(value: DisMax) -> Self {
+        Self::DisMax(value)
+    }
+}
+
+impl Serialize for DisMax {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(Serialize)]
+        struct Parts<'a> {
+            queries: &'a Vec<Criterion>,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            tie_breaker: Option<f64>,
+        }
+
+        #[derive(Serialize)]
+        struct DisMaxAst<'a> {
+            dis_max: Parts<'a>,
+        }
+
+        let dis_max = DisMaxAst {
+            dis_max: Parts {
+                queries: &self.queries,
+                tie_breaker: self.tie_breaker,
+            },
+        };
+
+        dis_max.serialize(serializer)
+    }
+}
+
+impl CollectFields for DisMax {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        self.queries.collect_fields(usage, fields);
+    }
+}
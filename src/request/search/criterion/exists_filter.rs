@@ -42,3 +42,9 @@ impl Serialize for ExistsFilter {
         map.end()
     }
 }
+
+impl CollectFields for ExistsFilter {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        self.field.collect_fields(usage, fields);
+    }
+}
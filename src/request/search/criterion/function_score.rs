@@ -0,0 +1,653 @@
+use super::*;
+use serde::{ser::SerializeMap, Serialize};
+
+/// Function Score
+///
+/// Selects with the criteria given to it and then adjusts the
+/// score of each document with functions, such as boosting the
+/// newer, nearer, or more popular ones.  As it only changes the
+/// score it should be added with [ScoringBuilder::score_with].
+///
+/// ```rust
+/// use elastic_lens::prelude::*;
+/// use elastic_lens::request::search::{
+///     BoostMode, DecayFunction, FieldValueFactor, GeoPoint, Modifier, ScoreMode,
+/// };
+///
+/// let mut search = Search::default();
+///
+/// search.score_with(function_score(|scoring| {
+///     scoring.score_with(field("title").matches("coffee"));
+///
+///     scoring.function(DecayFunction::gauss("published_at", "30d").origin("now"));
+///
+///     scoring.function(
+///         DecayFunction::exp("location", "2km").geo_origin(GeoPoint::new(40.7, -74.0)),
+///     );
+///
+///     scoring.function(FieldValueFactor::new("likes").modifier(Modifier::Log1p));
+///
+///     scoring.weight_when(2.0, |filter| {
+///         filter.with(field("featured").contains(true));
+///     });
+///
+///     scoring.set_score_mode(ScoreMode::Sum);
+///     scoring.set_boost_mode(BoostMode::Multiply);
+/// }));
+/// ```
+///
+pub fn function_score<FN>(mut func: FN) -> FunctionScore
+where
+    FN: FnMut(&mut FunctionScore),
+{
+    let mut function_score = FunctionScore::default();
+    func(&mut function_score);
+    function_score
+}
+
+/// Criteria with functions which adjust the score, built
+/// by [function_score]
+#[derive(Debug, Clone, Default)]
+pub struct FunctionScore {
+    query: AllMatch,
+    functions: Vec<ScoreFunction>,
+    score_mode: Option<ScoreMode>,
+    boost_mode: Option<BoostMode>,
+    max_boost: Option<f64>,
+    min_score: Option<f64>,
+    boost: Option<f64>,
+}
+
+/// A single function of a [FunctionScore], which can be
+/// limited to documents matching a filter and weighted
+#[derive(Debug, Clone)]
+pub struct ScoreFunction {
+    kind: Option<FunctionKind>,
+    filter: Option<AllMatch>,
+    weight: Option<f64>,
+}
+
+#[derive(Debug, Clone)]
+enum FunctionKind {
+    Decay(DecayFunction),
+    FieldValueFactor(FieldValueFactor),
+    RandomScore(RandomScore),
+}
+
+/// Decay Function
+///
+/// Scores documents by how far a numeric, date or geo field is
+/// from the origin.  Within the offset documents get the full
+/// score, which then falls to the decay at the scale beyond it.
+///
+#[derive(Debug, Clone)]
+pub struct DecayFunction {
+    kind: DecayKind,
+    field: Field,
+    origin: Option<DecayOrigin>,
+    scale: ScalarValue,
+    offset: Option<ScalarValue>,
+    decay: Option<f64>,
+}
+
+/// The shape of the falloff of a [DecayFunction]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DecayKind {
+    /// falls slowly, then quickly and then slowly again
+    Gauss,
+
+    /// falls at a constant rate down to zero
+    Linear,
+
+    /// falls quickly and then slowly
+    Exp,
+}
+
+#[derive(Debug, Clone)]
+enum DecayOrigin {
+    Value(ScalarValue),
+    Point(GeoPoint),
+}
+
+/// Scores by the value of a numeric field
+#[derive(Debug, Clone)]
+pub struct FieldValueFactor {
+    field: Field,
+    factor: Option<f64>,
+    modifier: Option<Modifier>,
+    missing: Option<f64>,
+}
+
+/// What is done to a field value before it is scored
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Modifier {
+    /// the value as is
+    None,
+    /// log10 of the value
+    Log,
+    /// log10 of the value plus one
+    Log1p,
+    /// log10 of the value plus two
+    Log2p,
+    /// natural log of the value
+    Ln,
+    /// natural log of the value plus one
+    Ln1p,
+    /// natural log of the value plus two
+    Ln2p,
+    /// the value squared
+    Square,
+    /// square root of the value
+    Sqrt,
+    /// one over the value
+    Reciprocal,
+}
+
+/// Scores randomly, which is the same for each document
+/// across searches when given a seed
+#[derive(Debug, Clone, Default)]
+pub struct RandomScore {
+    seed: Option<ScalarValue>,
+    field: Option<Field>,
+}
+
+/// How the scores of the functions are combined
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScoreMode {
+    /// multiplied together, the default
+    Multiply,
+    /// added together
+    Sum,
+    /// averaged
+    Avg,
+    /// the first function with a matching filter
+    First,
+    /// the highest score
+    Max,
+    /// the lowest score
+    Min,
+}
+
+/// How the function score is combined with the score
+/// of the query
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BoostMode {
+    /// multiplied together, the default
+    Multiply,
+    /// only the function score is used
+    Replace,
+    /// added together
+    Sum,
+    /// averaged
+    Avg,
+    /// the higher of the two
+    Max,
+    /// the lower of the two
+    Min,
+}
+
+impl FunctionScore {
+    /// adds a function to score documents with
+    pub fn function<F: Into<ScoreFunction>>(&mut self, function: F) {
+        self.functions.push(function.into());
+    }
+
+    /// Function When
+    ///
+    /// Adds a function which only scores documents matching
+    /// the filter, built the same as any other criteria.
+    ///
+    pub fn function_when<F, FN>(&mut self, function: F, filter: FN)
+    where
+        F: Into<ScoreFunction>,
+        FN: FnMut(&mut AllMatch),
+    {
+        self.functions.push(function.into().when(filter));
+    }
+
+    /// Weight When
+    ///
+    /// Multiplies the score of documents matching the filter by
+    /// the weight, such as doubling the score of featured items.
+    ///
+    pub fn weight_when<FN>(&mut self, weight: f64, filter: FN)
+    where
+        FN: FnMut(&mut AllMatch),
+    {
+        self.functions
+            .push(ScoreFunction::weight(weight).when(filter));
+    }
+
+    /// how the scores of the functions are combined
+    pub fn set_score_mode(&mut self, mode: ScoreMode) {
+        self.score_mode = Some(mode);
+    }
+
+    /// how the function score is combined with the query score
+    pub fn set_boost_mode(&mut self, mode: BoostMode) {
+        self.boost_mode = Some(mode);
+    }
+
+    /// most the function score can be
+    pub fn set_max_boost(&mut self, max: f64) {
+        self.max_boost = Some(max);
+    }
+
+    /// documents scoring less than this are left out
+    pub fn set_min_score(&mut self, min: f64) {
+        self.min_score = Some(min);
+    }
+}
+
+impl ScoreFunction {
+    /// A function which multiplies the score by the weight
+    pub fn weight(weight: f64) -> Self {
+        Self {
+            kind: None,
+            filter: None,
+            weight: Some(weight),
+        }
+    }
+
+    /// multiplies the score of this function by the weight
+    pub fn weighted(mut self, weight: f64) -> Self {
+        self.weight = Some(weight);
+        self
+    }
+
+    /// only score documents which match the filter
+    pub fn when<FN>(mut self, func: FN) -> Self
+    where
+        FN: FnMut(&mut AllMatch),
+    {
+        self.filter = Some(if_all_match(func));
+        self
+    }
+}
+
+impl DecayFunction {
+    /// Creates a DecayFunction, where the scale is how far
+    /// from the origin the score falls to the decay
+    pub fn new<F, V>(kind: DecayKind, field: F, scale: V) -> Self
+    where
+        F: Into<Field>,
+        V: Into<ScalarValue>,
+    {
+        Self {
+            kind,
+            field: field.into(),
+            origin: None,
+            scale: scale.into(),
+            offset: None,
+            decay: None,
+        }
+    }
+
+    /// DecayFunction with a gauss falloff
+    pub fn gauss<F: Into<Field>, V: Into<ScalarValue>>(field: F, scale: V) -> Self {
+        Self::new(DecayKind::Gauss, field, scale)
+    }
+
+    /// DecayFunction with a linear falloff
+    pub fn linear<F: Into<Field>, V: Into<ScalarValue>>(field: F, scale: V) -> Self {
+        Self::new(DecayKind::Linear, field, scale)
+    }
+
+    /// DecayFunction with an exponential falloff
+    pub fn exp<F: Into<Field>, V: Into<ScalarValue>>(field: F, scale: V) -> Self {
+        Self::new(DecayKind::Exp, field, scale)
+    }
+
+    /// where the distance is measured from, which is required
+    /// for numeric fields and defaults to now for dates
+    pub fn origin<V: Into<ScalarValue>>(mut self, origin: V) -> Self {
+        self.origin = Some(DecayOrigin::Value(origin.into()));
+        self
+    }
+
+    /// where the distance is measured from for geo fields
+    pub fn geo_origin<P: IntoGeoPoint>(mut self, origin: P) -> Self {
+        self.origin = Some(DecayOrigin::Point(origin.into_geo_point()));
+        self
+    }
+
+    /// how far from the origin documents get the full score
+    pub fn offset<V: Into<ScalarValue>>(mut self, offset: V) -> Self {
+        self.offset = Some(offset.into());
+        self
+    }
+
+    /// score at the scale away from the origin, `0.5` by default
+    pub fn decay(mut self, decay: f64) -> Self {
+        self.decay = Some(decay);
+        self
+    }
+}
+
+impl FieldValueFactor {
+    /// Creates a FieldValueFactor
+    pub fn new<F: Into<Field>>(field: F) -> Self {
+        Self {
+            field: field.into(),
+            factor: None,
+            modifier: None,
+            missing: None,
+        }
+    }
+
+    /// multiplied with the value before the modifier
+    pub fn factor(mut self, factor: f64) -> Self {
+        self.factor = Some(factor);
+        self
+    }
+
+    /// what is done to the value before it is scored
+    pub fn modifier(mut self, modifier: Modifier) -> Self {
+        self.modifier = Some(modifier);
+        self
+    }
+
+    /// value used for documents without the field
+    pub fn missing(mut self, missing: f64) -> Self {
+        self.missing = Some(missing);
+        self
+    }
+}
+
+impl RandomScore {
+    /// Creates a RandomScore which is different every search
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Seed
+    ///
+    /// Keeps the scores the same across searches with the same
+    /// seed, such as a user id.  The field should have a unique
+    /// value per document, `_seq_no` is a common choice.
+    ///
+    pub fn seed<V: Into<ScalarValue>, F: Into<Field>>(mut self, seed: V, field: F) -> Self {
+        self.seed = Some(seed.into());
+        self.field = Some(field.into());
+        self
+    }
+}
+
+/// Score Function Builder
+///
+/// Allows any function to be weighted or limited to a filter
+/// without having to turn it into a [ScoreFunction] first.
+///
+pub trait ScoreFunctionBuilder: Into<ScoreFunction> {
+    /// multiplies the score of this function by the weight
+    fn weighted(self, weight: f64) -> ScoreFunction {
+        self.into().weighted(weight)
+    }
+
+    /// only score documents which match the filter
+    fn when<FN: FnMut(&mut AllMatch)>(self, func: FN) -> ScoreFunction {
+        self.into().when(func)
+    }
+}
+
+impl ScoreFunctionBuilder for DecayFunction {}
+impl ScoreFunctionBuilder for FieldValueFactor {}
+impl ScoreFunctionBuilder for RandomScore {}
+
+impl From<DecayFunction> for ScoreFunction {
+    fn from(value: DecayFunction) -> Self {
+        Self {
+            kind: Some(FunctionKind::Decay(value)),
+            filter: None,
+            weight: None,
+        }
+    }
+}
+
+impl From<FieldValueFactor> for ScoreFunction {
+    fn from(value: FieldValueFactor) -> Self {
+        Self {
+            kind: Some(FunctionKind::FieldValueFactor(value)),
+            filter: None,
+            weight: None,
+        }
+    }
+}
+
+impl From<RandomScore> for ScoreFunction {
+    fn from(value: RandomScore) -> Self {
+        Self {
+            kind: Some(FunctionKind::RandomScore(value)),
+            filter: None,
+            weight: None,
+        }
+    }
+}
+
+impl CriteriaBuilder for FunctionScore {
+    type Bucket = NormalBucket;
+
+    fn positive_criteria_mut(&mut self) -> &mut Vec<Criterion> {
+        self.query.positive_criteria_mut()
+    }
+
+    fn negative_criteria_mut(&mut self) -> &mut Vec<Criterion> {
+        self.query.negative_criteria_mut()
+    }
+}
+
+impl ScoringBuilder for FunctionScore {
+    fn scoring_criteria_mut(&mut self) -> &mut ScoringCriteria {
+        self.query.scoring_criteria_mut()
+    }
+
+    /// multiplier applied to the whole function score,
+    /// rather than to the criteria it selects with
+    fn set_boost(&mut self, boost: f64) {
+        self.boost = Some(boost);
+    }
+}
+
+impl CriterionData for FunctionScore {}
+
+impl From<FunctionScore> for Criterion {
+    fn from(value: FunctionScore) -> Self {
+        Self::FunctionScore(value)
+    }
+}
+
+impl Serialize for DecayFunction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            origin: Option<&'a DecayOrigin>,
+
+            scale: &'a ScalarValue,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            offset: Option<&'a ScalarValue>,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            decay: Option<f64>,
+        }
+
+        let params = Params {
+            origin: self.origin.as_ref(),
+            scale: &self.scale,
+            offset: self.offset.as_ref(),
+            decay: self.decay,
+        };
+
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(&self.field, &params)?;
+        map.end()
+    }
+}
+
+impl Serialize for DecayOrigin {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Value(value) => value.serialize(serializer),
+            Self::Point(point) => point.serialize(serializer),
+        }
+    }
+}
+
+impl Serialize for FieldValueFactor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            field: &'a Field,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            factor: Option<f64>,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            modifier: Option<Modifier>,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            missing: Option<f64>,
+        }
+
+        Params {
+            field: &self.field,
+            factor: self.factor,
+            modifier: self.modifier,
+            missing: self.missing,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl Serialize for RandomScore {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            seed: Option<&'a ScalarValue>,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            field: Option<&'a Field>,
+        }
+
+        Params {
+            seed: self.seed.as_ref(),
+            field: self.field.as_ref(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl Serialize for ScoreFunction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+
+        match &self.kind {
+            Some(FunctionKind::Decay(decay)) => map.serialize_entry(&decay.kind, decay)?,
+            Some(FunctionKind::FieldValueFactor(factor)) => {
+                map.serialize_entry("field_value_factor", factor)?
+            }
+            Some(FunctionKind::RandomScore(random)) => {
+                map.serialize_entry("random_score", random)?
+            }
+            None => {}
+        }
+
+        if let Some(filter) = self.filter.as_ref().filter(|filter| filter.has_data()) {
+            map.serialize_entry("filter", filter)?;
+        }
+
+        if let Some(weight) = self.weight {
+            map.serialize_entry("weight", &weight)?;
+        }
+
+        map.end()
+    }
+}
+
+impl Serialize for FunctionScore {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            query: Option<&'a AllMatch>,
+
+            #[serde(skip_serializing_if = "Vec::is_empty")]
+            functions: &'a Vec<ScoreFunction>,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            score_mode: Option<ScoreMode>,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            boost_mode: Option<BoostMode>,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            max_boost: Option<f64>,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            min_score: Option<f64>,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            boost: Option<f64>,
+        }
+
+        let params = Params {
+            query: Some(&self.query).filter(|query| query.has_data()),
+            functions: &self.functions,
+            score_mode: self.score_mode,
+            boost_mode: self.boost_mode,
+            max_boost: self.max_boost,
+            min_score: self.min_score,
+            boost: self.boost,
+        };
+
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("function_score", &params)?;
+        map.end()
+    }
+}
+
+impl CollectFields for ScoreFunction {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        match &self.kind {
+            Some(FunctionKind::Decay(decay)) => decay.field.collect_fields(usage, fields),
+            Some(FunctionKind::FieldValueFactor(factor)) => {
+                factor.field.collect_fields(usage, fields)
+            }
+            Some(FunctionKind::RandomScore(random)) => random.field.collect_fields(usage, fields),
+            None => {}
+        }
+
+        self.filter.collect_fields(usage, fields);
+    }
+}
+
+impl CollectFields for FunctionScore {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        self.query.collect_fields(usage, fields);
+        self.functions.collect_fields(usage, fields);
+    }
+}
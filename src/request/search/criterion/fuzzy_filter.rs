@@ -0,0 +1,112 @@
+use super::*;
+use serde::Serialize;
+use std::borrow::Cow;
+
+/// Selects if a value of the field is within a few single
+/// character edits of the given value, such as a typo.
+#[derive(Debug, Clone)]
+pub struct FuzzyFilter {
+    field: Field,
+    value: Cow<'static, str>,
+    fuzziness: Option<Fuzziness>,
+    prefix_length: Option<u32>,
+    max_expansions: Option<u32>,
+}
+
+impl FuzzyFilter {
+    /// Creates a FuzzyFilter
+    pub fn new<F, V>(field: F, value: V) -> Self
+    where
+        F: Into<Field>,
+        V: Into<Cow<'static, str>>,
+    {
+        Self {
+            field: field.into(),
+            value: value.into(),
+            fuzziness: None,
+            prefix_length: None,
+            max_expansions: None,
+        }
+    }
+
+    /// how many edits are allowed, automatic by default
+    pub fn fuzziness(mut self, fuzziness: Fuzziness) -> Self {
+        self.fuzziness = Some(fuzziness);
+        self
+    }
+
+    /// how many characters at the start must match exactly
+    pub fn prefix_length(mut self, length: u32) -> Self {
+        self.prefix_length = Some(length);
+        self
+    }
+
+    /// most variations of the value to search for
+    pub fn max_expansions(mut self, max: u32) -> Self {
+        self.max_expansions = Some(max);
+        self
+    }
+}
+
+impl CriterionData for FuzzyFilter {}
+
+impl From<FuzzyFilter> for Criterion {
+    fn from(value: FuzzyFilter) -> Self {
+        Self::Fuzzy(value)
+    }
+}
+
+impl Serialize for FuzzyFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        #[derive(Serialize)]
+        struct Params<'a> {
+            value: &'a str,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            fuzziness: Option<Fuzziness>,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            prefix_length: Option<u32>,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            max_expansions: Option<u32>,
+        }
+
+        struct FieldContainer<'a> {
+            filter: &'a FuzzyFilter,
+        }
+
+        impl<'a> Serialize for FieldContainer<'a> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                let params = Params {
+                    value: &self.filter.value,
+                    fuzziness: self.filter.fuzziness,
+                    prefix_length: self.filter.prefix_length,
+                    max_expansions: self.filter.max_expansions,
+                };
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(&self.filter.field, &params)?;
+                map.end()
+            }
+        }
+
+        let container = FieldContainer { filter: self };
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("fuzzy", &container)?;
+        map.end()
+    }
+}
+
+impl CollectFields for FuzzyFilter {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        self.field.collect_fields(usage, fields);
+    }
+}
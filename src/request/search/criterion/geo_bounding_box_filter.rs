@@ -0,0 +1,96 @@
+use super::*;
+use serde::Serialize;
+
+/// Matches geo_point values inside of a rectangle, such
+/// as the viewport of a map.
+#[derive(Debug, Clone)]
+pub struct GeoBoundingBoxFilter {
+    field: Field,
+    top_left: GeoPoint,
+    bottom_right: GeoPoint,
+}
+
+impl GeoBoundingBoxFilter {
+    /// Creates a GeoBoundingBoxFilter by the top left
+    /// and bottom right corners of the box
+    pub fn new<F, T, B>(field: F, top_left: T, bottom_right: B) -> Self
+    where
+        F: Into<Field>,
+        T: IntoGeoPoint,
+        B: IntoGeoPoint,
+    {
+        Self {
+            field: field.into(),
+            top_left: top_left.into_geo_point(),
+            bottom_right: bottom_right.into_geo_point(),
+        }
+    }
+
+    /// field filter is targeting
+    pub fn field(&self) -> &Field {
+        &self.field
+    }
+
+    /// north west corner of the box
+    pub fn top_left(&self) -> GeoPoint {
+        self.top_left
+    }
+
+    /// south east corner of the box
+    pub fn bottom_right(&self) -> GeoPoint {
+        self.bottom_right
+    }
+}
+
+impl CriterionData for GeoBoundingBoxFilter {}
+
+impl From<GeoBoundingBoxFilter> for Criterion {
+    fn from(value: GeoBoundingBoxFilter) -> Self {
+        Self::GeoBoundingBox(value)
+    }
+}
+
+impl Serialize for GeoBoundingBoxFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        #[derive(Serialize)]
+        struct Corners {
+            top_left: GeoPoint,
+            bottom_right: GeoPoint,
+        }
+
+        struct FieldContainer<'a> {
+            filter: &'a GeoBoundingBoxFilter,
+        }
+
+        impl<'a> Serialize for FieldContainer<'a> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                let corners = Corners {
+                    top_left: self.filter.top_left,
+                    bottom_right: self.filter.bottom_right,
+                };
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(&self.filter.field, &corners)?;
+                map.end()
+            }
+        }
+
+        let container = FieldContainer { filter: self };
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("geo_bounding_box", &container)?;
+        map.end()
+    }
+}
+
+impl CollectFields for GeoBoundingBoxFilter {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        self.field.collect_fields(usage, fields);
+    }
+}
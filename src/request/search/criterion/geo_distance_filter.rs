@@ -71,27 +71,67 @@ impl GeoDistanceFilterBuilder<1> {
             point: self.point,
         }
     }
+
+    /// the distance away, already in it's units, which can be
+    /// fractional but fails if negative, infinite or not a number
+    pub fn within_distance(
+        self,
+        distance: Distance,
+    ) -> Result<GeoDistanceFilterBuilder<3>, DistanceError> {
+        Ok(GeoDistanceFilterBuilder {
+            unit: None,
+            field: self.field,
+            distance: Some(distance.validate()?),
+            point: self.point,
+        })
+    }
 }
 
 impl GeoDistanceFilterBuilder<2> {
-    /// the units in miles
-    pub fn miles(self) -> GeoDistanceFilterBuilder<3> {
+    /// the units in any of the [DistanceUnit]s
+    pub fn measured_in(self, unit: DistanceUnit) -> GeoDistanceFilterBuilder<3> {
         GeoDistanceFilterBuilder {
             unit: None,
             field: self.field,
-            distance: Some(Distance::Miles(self.unit.unwrap())),
+            distance: Some(Distance::whole(self.unit.unwrap(), unit)),
             point: self.point,
         }
     }
 
+    /// the units in miles
+    pub fn miles(self) -> GeoDistanceFilterBuilder<3> {
+        self.measured_in(DistanceUnit::Miles)
+    }
+
     /// the units in kilometers
+    #[deprecated(note = "use kilometers")]
     pub fn kilomenters(self) -> GeoDistanceFilterBuilder<3> {
-        GeoDistanceFilterBuilder {
-            unit: None,
-            field: self.field,
-            distance: Some(Distance::Kilometers(self.unit.unwrap())),
-            point: self.point,
-        }
+        self.measured_in(DistanceUnit::Kilometers)
+    }
+
+    /// the units in kilometers
+    pub fn kilometers(self) -> GeoDistanceFilterBuilder<3> {
+        self.measured_in(DistanceUnit::Kilometers)
+    }
+
+    /// the units in meters
+    pub fn meters(self) -> GeoDistanceFilterBuilder<3> {
+        self.measured_in(DistanceUnit::Meters)
+    }
+
+    /// the units in yards
+    pub fn yards(self) -> GeoDistanceFilterBuilder<3> {
+        self.measured_in(DistanceUnit::Yards)
+    }
+
+    /// the units in feet
+    pub fn feet(self) -> GeoDistanceFilterBuilder<3> {
+        self.measured_in(DistanceUnit::Feet)
+    }
+
+    /// the units in nautical miles
+    pub fn nautical_miles(self) -> GeoDistanceFilterBuilder<3> {
+        self.measured_in(DistanceUnit::NauticalMiles)
     }
 }
 
@@ -115,27 +155,8 @@ impl Serialize for GeoDistanceFilter {
     {
         use serde::ser::SerializeMap;
 
-        struct DistanceString(Distance);
-
-        impl Serialize for DistanceString {
-            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-            where
-                S: serde::Serializer,
-            {
-                use std::fmt::Write;
-                let mut string = arraystring::SmallString::new();
-                match self.0 {
-                    Distance::Miles(amount) => write!(&mut string, "{}mi", amount)
-                        .or_else(|_| Err(serde::ser::Error::custom("Cannot write miles")))?,
-                    Distance::Kilometers(amount) => write!(&mut string, "{}km", amount)
-                        .or_else(|_| Err(serde::ser::Error::custom("Cannot write kilomenters")))?,
-                };
-                serializer.serialize_str(&string)
-            }
-        }
-
         struct DistanceFieldLocation<'a> {
-            distance: DistanceString,
+            distance: Distance,
             field: &'a Field,
             location: GeoPoint,
         }
@@ -153,7 +174,7 @@ impl Serialize for GeoDistanceFilter {
         }
 
         let dfl = DistanceFieldLocation {
-            distance: DistanceString(self.distance),
+            distance: self.distance,
             field: &self.field,
             location: self.point,
         };
@@ -162,3 +183,9 @@ impl Serialize for GeoDistanceFilter {
         map.end()
     }
 }
+
+impl CollectFields for GeoDistanceFilter {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        self.field.collect_fields(usage, fields);
+    }
+}
@@ -0,0 +1,90 @@
+use super::*;
+use serde::Serialize;
+
+/// Compares geo_shape values, or geo_point values on
+/// Elasticsearch 7.11 and newer, against a [GeoShape].
+#[derive(Debug, Clone)]
+pub struct GeoShapeFilter {
+    field: Field,
+    shape: GeoShape,
+    relation: GeoShapeRelation,
+}
+
+impl GeoShapeFilter {
+    /// Creates a GeoShapeFilter
+    pub fn new<F: Into<Field>>(field: F, relation: GeoShapeRelation, shape: GeoShape) -> Self {
+        Self {
+            field: field.into(),
+            shape,
+            relation,
+        }
+    }
+
+    /// field filter is targeting
+    pub fn field(&self) -> &Field {
+        &self.field
+    }
+
+    /// the shape the field is compared against
+    pub fn shape(&self) -> &GeoShape {
+        &self.shape
+    }
+
+    /// how the field is compared to the shape
+    pub fn relation(&self) -> GeoShapeRelation {
+        self.relation
+    }
+}
+
+impl CriterionData for GeoShapeFilter {}
+
+impl From<GeoShapeFilter> for Criterion {
+    fn from(value: GeoShapeFilter) -> Self {
+        Self::GeoShape(value)
+    }
+}
+
+impl Serialize for GeoShapeFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        #[derive(Serialize)]
+        struct Params<'a> {
+            shape: &'a GeoShape,
+            relation: GeoShapeRelation,
+        }
+
+        struct FieldContainer<'a> {
+            filter: &'a GeoShapeFilter,
+        }
+
+        impl<'a> Serialize for FieldContainer<'a> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                let params = Params {
+                    shape: &self.filter.shape,
+                    relation: self.filter.relation,
+                };
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(&self.filter.field, &params)?;
+                map.end()
+            }
+        }
+
+        let container = FieldContainer { filter: self };
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("geo_shape", &container)?;
+        map.end()
+    }
+}
+
+impl CollectFields for GeoShapeFilter {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        self.field.collect_fields(usage, fields);
+    }
+}
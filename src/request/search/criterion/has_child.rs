@@ -0,0 +1,168 @@
+use super::*;
+use serde::Serialize;
+use std::borrow::Cow;
+
+/// Has Child
+///
+/// Selects parent documents of a `join` field which have at
+/// least one child of the type matching the criteria given to
+/// it.  Children are scored with any scoring criteria and the
+/// [ChildScoreMode] decides how that becomes the parent's score.
+///
+/// ```rust
+/// use elastic_lens::prelude::*;
+/// use elastic_lens::request::search::{ChildScoreMode, InnerHits};
+///
+/// let mut search = Search::default();
+///
+/// search.with(has_child("line_item", |child| {
+///     child.with(field("sku").contains("ABC-123"));
+///     child.set_min_children(2);
+///     child.set_score_mode(ChildScoreMode::Sum);
+///     child.set_inner_hits(InnerHits::new().size(5));
+/// }));
+/// ```
+///
+pub fn has_child<T, F>(child_type: T, mut func: F) -> HasChild
+where
+    T: Into<Cow<'static, str>>,
+    F: FnMut(&mut HasChild),
+{
+    let mut has_child = HasChild {
+        child_type: child_type.into(),
+        query: AllMatch::default(),
+        score_mode: None,
+        min_children: None,
+        max_children: None,
+        inner_hits: None,
+    };
+    func(&mut has_child);
+    has_child
+}
+
+/// Selects parents by their children, built by [has_child]
+#[derive(Debug, Clone)]
+pub struct HasChild {
+    child_type: Cow<'static, str>,
+    query: AllMatch,
+    score_mode: Option<ChildScoreMode>,
+    min_children: Option<u32>,
+    max_children: Option<u32>,
+    inner_hits: Option<InnerHits>,
+}
+
+/// How the scores of matching children become the
+/// score of the parent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChildScoreMode {
+    /// children don't count towards the score, the default
+    None,
+    /// average of the children
+    Avg,
+    /// sum of the children
+    Sum,
+    /// highest scoring child
+    Max,
+    /// lowest scoring child
+    Min,
+}
+
+impl HasChild {
+    /// how the scores of the children become the parent score
+    pub fn set_score_mode(&mut self, mode: ChildScoreMode) {
+        self.score_mode = Some(mode);
+    }
+
+    /// fewest matching children a parent can have
+    pub fn set_min_children(&mut self, min: u32) {
+        self.min_children = Some(min);
+    }
+
+    /// most matching children a parent can have
+    pub fn set_max_children(&mut self, max: u32) {
+        self.max_children = Some(max);
+    }
+
+    /// return the matching children with each parent
+    pub fn set_inner_hits(&mut self, inner_hits: InnerHits) {
+        self.inner_hits = Some(inner_hits);
+    }
+}
+
+impl CriteriaBuilder for HasChild {
+    type Bucket = NormalBucket;
+
+    fn positive_criteria_mut(&mut self) -> &mut Vec<Criterion> {
+        self.query.positive_criteria_mut()
+    }
+
+    fn negative_criteria_mut(&mut self) -> &mut Vec<Criterion> {
+        self.query.negative_criteria_mut()
+    }
+}
+
+impl ScoringBuilder for HasChild {
+    fn scoring_criteria_mut(&mut self) -> &mut ScoringCriteria {
+        self.query.scoring_criteria_mut()
+    }
+}
+
+impl CriterionData for HasChild {}
+
+impl From<HasChild> for Criterion {
+    fn from(value: HasChild) -> Self {
+        Self::HasChild(value)
+    }
+}
+
+impl Serialize for HasChild {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(Serialize)]
+        struct Parts<'a> {
+            #[serde(rename = "type")]
+            child_type: &'a str,
+
+            query: RequiredQuery<'a>,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            score_mode: Option<ChildScoreMode>,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            min_children: Option<u32>,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            max_children: Option<u32>,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            inner_hits: Option<&'a InnerHits>,
+        }
+
+        #[derive(Serialize)]
+        struct HasChildAst<'a> {
+            has_child: Parts<'a>,
+        }
+
+        let has_child = HasChildAst {
+            has_child: Parts {
+                child_type: &self.child_type,
+                query: RequiredQuery(&self.query),
+                score_mode: self.score_mode,
+                min_children: self.min_children,
+                max_children: self.max_children,
+                inner_hits: self.inner_hits.as_ref(),
+            },
+        };
+
+        has_child.serialize(serializer)
+    }
+}
+
+impl CollectFields for HasChild {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        self.query.collect_fields(usage, fields);
+    }
+}
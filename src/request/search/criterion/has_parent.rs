@@ -0,0 +1,123 @@
+use super::*;
+use serde::Serialize;
+use std::borrow::Cow;
+
+/// Has Parent
+///
+/// Selects child documents of a `join` field whose parent of
+/// the type matches the criteria given to it.
+///
+/// ```rust
+/// use elastic_lens::prelude::*;
+///
+/// let mut search = Search::default();
+///
+/// search.with(has_parent("order", |parent| {
+///     parent.with(field("status").contains("shipped"));
+/// }));
+/// ```
+///
+pub fn has_parent<T, F>(parent_type: T, mut func: F) -> HasParent
+where
+    T: Into<Cow<'static, str>>,
+    F: FnMut(&mut HasParent),
+{
+    let mut has_parent = HasParent {
+        parent_type: parent_type.into(),
+        query: AllMatch::default(),
+        score: None,
+        inner_hits: None,
+    };
+    func(&mut has_parent);
+    has_parent
+}
+
+/// Selects children by their parent, built by [has_parent]
+#[derive(Debug, Clone)]
+pub struct HasParent {
+    parent_type: Cow<'static, str>,
+    query: AllMatch,
+    score: Option<bool>,
+    inner_hits: Option<InnerHits>,
+}
+
+impl HasParent {
+    /// give children the score of their parent instead of
+    /// the same score for every match
+    pub fn use_parent_score(&mut self) {
+        self.score = Some(true);
+    }
+
+    /// return the matching parent with each child
+    pub fn set_inner_hits(&mut self, inner_hits: InnerHits) {
+        self.inner_hits = Some(inner_hits);
+    }
+}
+
+impl CriteriaBuilder for HasParent {
+    type Bucket = NormalBucket;
+
+    fn positive_criteria_mut(&mut self) -> &mut Vec<Criterion> {
+        self.query.positive_criteria_mut()
+    }
+
+    fn negative_criteria_mut(&mut self) -> &mut Vec<Criterion> {
+        self.query.negative_criteria_mut()
+    }
+}
+
+impl ScoringBuilder for HasParent {
+    fn scoring_criteria_mut(&mut self) -> &mut ScoringCriteria {
+        self.query.scoring_criteria_mut()
+    }
+}
+
+impl CriterionData for HasParent {}
+
+impl From<HasParent> for Criterion {
+    fn from(value: HasParent) -> Self {
+        Self::HasParent(value)
+    }
+}
+
+impl Serialize for HasParent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(Serialize)]
+        struct Parts<'a> {
+            parent_type: &'a str,
+
+            query: RequiredQuery<'a>,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            score: Option<bool>,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            inner_hits: Option<&'a InnerHits>,
+        }
+
+        #[derive(Serialize)]
+        struct HasParentAst<'a> {
+            has_parent: Parts<'a>,
+        }
+
+        let has_parent = HasParentAst {
+            has_parent: Parts {
+                parent_type: &self.parent_type,
+                query: RequiredQuery(&self.query),
+                score: self.score,
+                inner_hits: self.inner_hits.as_ref(),
+            },
+        };
+
+        has_parent.serialize(serializer)
+    }
+}
+
+impl CollectFields for HasParent {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        self.query.collect_fields(usage, fields);
+    }
+}
@@ -0,0 +1,66 @@
+use super::*;
+use serde::Serialize;
+use std::borrow::Cow;
+
+/// Selects documents by their id
+pub fn ids<I, S>(ids: I) -> IdsFilter
+where
+    I: IntoIterator<Item = S>,
+    S: Into<Cow<'static, str>>,
+{
+    IdsFilter::new(ids)
+}
+
+/// Selects if the document has any of the ids
+#[derive(Debug, Clone)]
+pub struct IdsFilter {
+    values: Vec<Cow<'static, str>>,
+}
+
+impl IdsFilter {
+    /// Creates an IdsFilter
+    pub fn new<I, S>(ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Cow<'static, str>>,
+    {
+        Self {
+            values: ids.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl CriterionData for IdsFilter {}
+
+impl From<IdsFilter> for Criterion {
+    fn from(value: IdsFilter) -> Self {
+        Self::Ids(value)
+    }
+}
+
+impl Serialize for IdsFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        #[derive(Serialize)]
+        struct Params<'a> {
+            values: &'a Vec<Cow<'static, str>>,
+        }
+
+        let params = Params {
+            values: &self.values,
+        };
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("ids", &params)?;
+        map.end()
+    }
+}
+
+// ids select by the `_id` metadata field, which isn't
+// one that shows up in the mapping of an index
+impl CollectFields for IdsFilter {
+    fn collect_fields<'a>(&'a self, _usage: FieldUsage, _fields: &mut Vec<FieldReference<'a>>) {}
+}
@@ -0,0 +1,47 @@
+use serde::Serialize;
+use std::borrow::Cow;
+
+/// Inner Hits
+///
+/// Asks for the related documents which caused a join or
+/// nested criterion to match, such as the children found by
+/// a [crate::request::search::HasChild], to be sent back
+/// along with each hit.
+///
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct InnerHits {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<Cow<'static, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from: Option<usize>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<usize>,
+}
+
+impl InnerHits {
+    /// Inner hits with the server defaults, the top three
+    /// named after the relation type or nested path
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// name the inner hits are returned under
+    pub fn name<S: Into<Cow<'static, str>>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// how many of the related documents to skip
+    pub fn from(mut self, from: usize) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// most related documents to return per hit
+    pub fn size(mut self, size: usize) -> Self {
+        self.size = Some(size);
+        self
+    }
+}
@@ -0,0 +1,235 @@
+use super::*;
+use serde::Serialize;
+use std::borrow::Cow;
+
+/// Full text match of a field, which analyzes the text the same
+/// way the field was and selects documents with any of the terms.
+#[derive(Debug, Clone)]
+pub struct MatchFilter {
+    field: Field,
+    query: Cow<'static, str>,
+    operator: Option<Operator>,
+    fuzziness: Option<Fuzziness>,
+}
+
+/// Full text match of a phrase, where the terms must be
+/// found in the field in the same order.
+#[derive(Debug, Clone)]
+pub struct MatchPhraseFilter {
+    field: Field,
+    query: Cow<'static, str>,
+    prefix: bool,
+    slop: Option<u32>,
+}
+
+/// How the terms of a full text query are combined
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Operator {
+    /// any of the terms must match, the default
+    Or,
+
+    /// every term must match
+    And,
+}
+
+/// How many single character edits a term can be away from
+/// another and still match
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fuzziness {
+    /// let the server decide by the length of the term
+    Auto,
+
+    /// a fixed number of edits, from `0` to `2`
+    Edits(u8),
+}
+
+impl MatchFilter {
+    /// Creates a MatchFilter
+    pub fn new<F, Q>(field: F, query: Q) -> Self
+    where
+        F: Into<Field>,
+        Q: Into<Cow<'static, str>>,
+    {
+        Self {
+            field: field.into(),
+            query: query.into(),
+            operator: None,
+            fuzziness: None,
+        }
+    }
+
+    /// how the terms of the text are combined
+    pub fn operator(mut self, operator: Operator) -> Self {
+        self.operator = Some(operator);
+        self
+    }
+
+    /// allow terms to match with typos
+    pub fn fuzziness(mut self, fuzziness: Fuzziness) -> Self {
+        self.fuzziness = Some(fuzziness);
+        self
+    }
+}
+
+impl MatchPhraseFilter {
+    /// Creates a MatchPhraseFilter
+    pub fn new<F, Q>(field: F, query: Q) -> Self
+    where
+        F: Into<Field>,
+        Q: Into<Cow<'static, str>>,
+    {
+        Self {
+            field: field.into(),
+            query: query.into(),
+            prefix: false,
+            slop: None,
+        }
+    }
+
+    /// Creates a MatchPhraseFilter where the last term of the
+    /// phrase is treated as a prefix, handy for search-as-you-type
+    pub fn prefix<F, Q>(field: F, query: Q) -> Self
+    where
+        F: Into<Field>,
+        Q: Into<Cow<'static, str>>,
+    {
+        Self {
+            prefix: true,
+            ..Self::new(field, query)
+        }
+    }
+
+    /// how many positions terms may be moved and still match
+    pub fn slop(mut self, slop: u32) -> Self {
+        self.slop = Some(slop);
+        self
+    }
+}
+
+impl CriterionData for MatchFilter {}
+impl CriterionData for MatchPhraseFilter {}
+
+impl From<MatchFilter> for Criterion {
+    fn from(value: MatchFilter) -> Self {
+        Self::Match(value)
+    }
+}
+
+impl From<MatchPhraseFilter> for Criterion {
+    fn from(value: MatchPhraseFilter) -> Self {
+        Self::MatchPhrase(value)
+    }
+}
+
+impl Serialize for Fuzziness {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Auto => serializer.serialize_str("AUTO"),
+            Self::Edits(edits) => serializer.serialize_u8(*edits),
+        }
+    }
+}
+
+impl Serialize for MatchFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        #[derive(Serialize)]
+        struct Params<'a> {
+            query: &'a str,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            operator: Option<Operator>,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            fuzziness: Option<Fuzziness>,
+        }
+
+        struct Inner<'a>(&'a Field, Params<'a>);
+
+        impl<'a> Serialize for Inner<'a> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(self.0, &self.1)?;
+                map.end()
+            }
+        }
+
+        let params = Params {
+            query: &self.query,
+            operator: self.operator,
+            fuzziness: self.fuzziness,
+        };
+
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("match", &Inner(&self.field, params))?;
+        map.end()
+    }
+}
+
+impl Serialize for MatchPhraseFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        #[derive(Serialize)]
+        struct Params<'a> {
+            query: &'a str,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            slop: Option<u32>,
+        }
+
+        struct Inner<'a>(&'a Field, Params<'a>);
+
+        impl<'a> Serialize for Inner<'a> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(self.0, &self.1)?;
+                map.end()
+            }
+        }
+
+        let params = Params {
+            query: &self.query,
+            slop: self.slop,
+        };
+
+        let key = if self.prefix {
+            "match_phrase_prefix"
+        } else {
+            "match_phrase"
+        };
+
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(key, &Inner(&self.field, params))?;
+        map.end()
+    }
+}
+
+impl CollectFields for MatchFilter {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        self.field.collect_fields(usage, fields);
+    }
+}
+
+impl CollectFields for MatchPhraseFilter {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        self.field.collect_fields(usage, fields);
+    }
+}
@@ -0,0 +1,190 @@
+use super::*;
+use serde::Serialize;
+use std::borrow::Cow;
+
+/// Multi Match
+///
+/// Starts a full text match of the text across several fields,
+/// which are added with [MultiMatch::field] and
+/// [MultiMatch::boosted_field].
+///
+/// ```rust
+/// use elastic_lens::prelude::*;
+/// use elastic_lens::request::search::{MultiMatchType, Operator};
+///
+/// let mut search = Search::default();
+///
+/// search.with(
+///     multi_match("red shirt")
+///         .boosted_field("title", 3.0)
+///         .field("description")
+///         .match_type(MultiMatchType::CrossFields)
+///         .operator(Operator::And)
+/// );
+/// ```
+///
+pub fn multi_match<Q: Into<Cow<'static, str>>>(query: Q) -> MultiMatch {
+    MultiMatch::new(query)
+}
+
+/// Full text match of the same text across several fields
+#[derive(Debug, Clone)]
+pub struct MultiMatch {
+    query: Cow<'static, str>,
+    fields: Vec<BoostedField>,
+    match_type: Option<MultiMatchType>,
+    operator: Option<Operator>,
+    fuzziness: Option<Fuzziness>,
+}
+
+/// a field searched for full text along with how much
+/// matches on it count towards the score
+#[derive(Debug, Clone)]
+pub(super) struct BoostedField {
+    pub(super) field: Field,
+    pub(super) boost: Option<f64>,
+}
+
+/// How the fields of a [MultiMatch] are searched and scored
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MultiMatchType {
+    /// scores by the single best matching field, the default
+    BestFields,
+
+    /// adds up the scores of every matching field, for when
+    /// the fields hold the same text analyzed differently
+    MostFields,
+
+    /// treats the fields as one big field, for when the terms
+    /// are spread across them such as a first and last name
+    CrossFields,
+
+    /// runs a phrase match on each field
+    Phrase,
+
+    /// runs a phrase prefix match on each field
+    PhrasePrefix,
+}
+
+impl MultiMatch {
+    /// Creates a MultiMatch without any fields, which
+    /// searches the default fields of the index
+    pub fn new<Q: Into<Cow<'static, str>>>(query: Q) -> Self {
+        Self {
+            query: query.into(),
+            fields: vec![],
+            match_type: None,
+            operator: None,
+            fuzziness: None,
+        }
+    }
+
+    /// search this field as well
+    pub fn field<F: Into<Field>>(mut self, field: F) -> Self {
+        self.fields.push(BoostedField {
+            field: field.into(),
+            boost: None,
+        });
+        self
+    }
+
+    /// Boosted Field
+    ///
+    /// Search this field as well with matches on it counting
+    /// towards the score by the boost, such as a title counting
+    /// three times as much as the description.
+    ///
+    pub fn boosted_field<F: Into<Field>>(mut self, field: F, boost: f64) -> Self {
+        self.fields.push(BoostedField {
+            field: field.into(),
+            boost: Some(boost),
+        });
+        self
+    }
+
+    /// how the fields are searched and scored
+    pub fn match_type(mut self, match_type: MultiMatchType) -> Self {
+        self.match_type = Some(match_type);
+        self
+    }
+
+    /// how the terms of the text are combined
+    pub fn operator(mut self, operator: Operator) -> Self {
+        self.operator = Some(operator);
+        self
+    }
+
+    /// allow terms to match with typos, which is not
+    /// supported by the cross field and phrase types
+    pub fn fuzziness(mut self, fuzziness: Fuzziness) -> Self {
+        self.fuzziness = Some(fuzziness);
+        self
+    }
+}
+
+impl CriterionData for MultiMatch {}
+
+impl From<MultiMatch> for Criterion {
+    fn from(value: MultiMatch) -> Self {
+        Self::MultiMatch(value)
+    }
+}
+
+impl Serialize for BoostedField {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self.boost {
+            None => self.field.serialize(serializer),
+            Some(boost) => serializer.collect_str(&format_args!("{}^{boost}", self.field.name())),
+        }
+    }
+}
+
+impl Serialize for MultiMatch {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        #[derive(Serialize)]
+        struct Params<'a> {
+            query: &'a str,
+
+            #[serde(skip_serializing_if = "Vec::is_empty")]
+            fields: &'a Vec<BoostedField>,
+
+            #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+            match_type: Option<MultiMatchType>,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            operator: Option<Operator>,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            fuzziness: Option<Fuzziness>,
+        }
+
+        let params = Params {
+            query: &self.query,
+            fields: &self.fields,
+            match_type: self.match_type,
+            operator: self.operator,
+            fuzziness: self.fuzziness,
+        };
+
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("multi_match", &params)?;
+        map.end()
+    }
+}
+
+impl CollectFields for MultiMatch {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        for boosted in &self.fields {
+            boosted.field.collect_fields(usage, fields);
+        }
+    }
+}
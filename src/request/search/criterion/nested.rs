@@ -8,9 +8,15 @@ use serde::Serialize;
 pub struct NestedFilter {
     criteria: AllMatch,
     path: Field,
+    inner_hits: Option<InnerHits>,
 }
 
 impl NestedFilter {
+    /// return the matching nested documents with each hit
+    pub fn set_inner_hits(&mut self, inner_hits: InnerHits) {
+        self.inner_hits = Some(inner_hits);
+    }
+
     pub(crate) fn has_data(&self) -> bool {
         self.criteria.has_data()
     }
@@ -25,6 +31,7 @@ where
     let mut nested = NestedFilter {
         path: field.into(),
         criteria: Default::default(),
+        inner_hits: None,
     };
     func(&mut nested);
     nested
@@ -57,6 +64,9 @@ impl Serialize for NestedFilter {
         struct Parts<'a> {
             query: &'a AllMatch,
             path: &'a Field,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            inner_hits: Option<&'a InnerHits>,
         }
 
         #[derive(Serialize)]
@@ -68,9 +78,17 @@ impl Serialize for NestedFilter {
             nested: Parts {
                 path: &self.path,
                 query: &self.criteria,
+                inner_hits: self.inner_hits.as_ref(),
             },
         };
 
         nested.serialize(serializer)
     }
 }
+
+impl CollectFields for NestedFilter {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        self.path.collect_fields(usage, fields);
+        self.criteria.collect_fields(usage, fields);
+    }
+}
@@ -43,11 +43,7 @@ impl Serialize for NotAll {
         S: serde::Serializer,
     {
         let node = ElasticsearchQuery {
-            bool: ElasticsearchBool {
-                must_not: Some(&self.criteria),
-                filter: None,
-                should: None,
-            },
+            bool: ElasticsearchBool::new(None, Some(&self.criteria), None),
         };
 
         node.serialize(serializer)
@@ -61,3 +57,9 @@ impl From<NotAll> for Criterion {
 }
 
 impl CriterionData for NotAll {}
+
+impl CollectFields for NotAll {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        self.criteria.collect_fields(usage, fields);
+    }
+}
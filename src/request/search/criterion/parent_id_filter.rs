@@ -0,0 +1,89 @@
+use super::*;
+use serde::Serialize;
+use std::borrow::Cow;
+
+/// Selects the children of the type which belong to the
+/// parent with the id
+///
+/// ```rust
+/// use elastic_lens::prelude::*;
+///
+/// let mut search = Search::default();
+/// search.with(parent_id("line_item", "order-42"));
+/// ```
+///
+pub fn parent_id<T, I>(child_type: T, id: I) -> ParentIdFilter
+where
+    T: Into<Cow<'static, str>>,
+    I: Into<Cow<'static, str>>,
+{
+    ParentIdFilter::new(child_type, id)
+}
+
+/// Children of a single parent of a `join` field
+#[derive(Debug, Clone)]
+pub struct ParentIdFilter {
+    child_type: Cow<'static, str>,
+    id: Cow<'static, str>,
+}
+
+impl ParentIdFilter {
+    /// Creates a ParentIdFilter
+    pub fn new<T, I>(child_type: T, id: I) -> Self
+    where
+        T: Into<Cow<'static, str>>,
+        I: Into<Cow<'static, str>>,
+    {
+        Self {
+            child_type: child_type.into(),
+            id: id.into(),
+        }
+    }
+
+    /// the type of the children selected
+    pub fn child_type(&self) -> &str {
+        &self.child_type
+    }
+
+    /// id of the parent
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl CriterionData for ParentIdFilter {}
+
+impl From<ParentIdFilter> for Criterion {
+    fn from(value: ParentIdFilter) -> Self {
+        Self::ParentId(value)
+    }
+}
+
+impl Serialize for ParentIdFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        #[derive(Serialize)]
+        struct Params<'a> {
+            #[serde(rename = "type")]
+            child_type: &'a str,
+            id: &'a str,
+        }
+
+        let params = Params {
+            child_type: &self.child_type,
+            id: &self.id,
+        };
+
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("parent_id", &params)?;
+        map.end()
+    }
+}
+
+impl CollectFields for ParentIdFilter {
+    fn collect_fields<'a>(&'a self, _usage: FieldUsage, _fields: &mut Vec<FieldReference<'a>>) {}
+}
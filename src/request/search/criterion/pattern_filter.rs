@@ -0,0 +1,116 @@
+use super::*;
+use serde::Serialize;
+use std::borrow::Cow;
+
+/// Selects if a value of the field matches a pattern, which
+/// is one of a prefix, wildcard or regular expression.  These
+/// work on the exact values of a field, the same as a term
+/// filter, so they are best used with keyword fields.
+#[derive(Debug, Clone)]
+pub struct PatternFilter {
+    field: Field,
+    pattern: Cow<'static, str>,
+    kind: PatternKind,
+    case_insensitive: bool,
+}
+
+/// The kinds of patterns a [PatternFilter] can match by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternKind {
+    /// the value starts with the pattern
+    Prefix,
+
+    /// the pattern can use `?` for any one character
+    /// and `*` for any number of characters
+    Wildcard,
+
+    /// the pattern is a Lucene regular expression
+    Regexp,
+}
+
+impl PatternKind {
+    fn key(&self) -> &'static str {
+        match self {
+            Self::Prefix => "prefix",
+            Self::Wildcard => "wildcard",
+            Self::Regexp => "regexp",
+        }
+    }
+}
+
+impl PatternFilter {
+    /// Creates a PatternFilter
+    pub fn new<F, P>(field: F, kind: PatternKind, pattern: P) -> Self
+    where
+        F: Into<Field>,
+        P: Into<Cow<'static, str>>,
+    {
+        Self {
+            field: field.into(),
+            pattern: pattern.into(),
+            kind,
+            case_insensitive: false,
+        }
+    }
+
+    /// match the pattern without regard to case
+    pub fn case_insensitive(mut self) -> Self {
+        self.case_insensitive = true;
+        self
+    }
+}
+
+impl CriterionData for PatternFilter {}
+
+impl From<PatternFilter> for Criterion {
+    fn from(value: PatternFilter) -> Self {
+        Self::Pattern(value)
+    }
+}
+
+impl Serialize for PatternFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        #[derive(Serialize)]
+        struct Params<'a> {
+            value: &'a str,
+
+            #[serde(skip_serializing_if = "std::ops::Not::not")]
+            case_insensitive: bool,
+        }
+
+        struct FieldContainer<'a> {
+            filter: &'a PatternFilter,
+        }
+
+        impl<'a> Serialize for FieldContainer<'a> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                let params = Params {
+                    value: &self.filter.pattern,
+                    case_insensitive: self.filter.case_insensitive,
+                };
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(&self.filter.field, &params)?;
+                map.end()
+            }
+        }
+
+        let container = FieldContainer { filter: self };
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(self.kind.key(), &container)?;
+        map.end()
+    }
+}
+
+impl CollectFields for PatternFilter {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        self.field.collect_fields(usage, fields);
+    }
+}
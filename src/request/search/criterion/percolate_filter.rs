@@ -0,0 +1,83 @@
+use super::*;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Selects the stored searches, kept in a `percolator` field,
+/// which match at least one of the given documents.  Each hit
+/// lists which documents it matched with
+/// [crate::response::DocumentHit::percolator_document_slots].
+#[derive(Debug, Clone)]
+pub struct PercolateFilter {
+    field: Field,
+    documents: Vec<Value>,
+}
+
+impl PercolateFilter {
+    /// Creates a PercolateFilter for already serialized documents
+    pub fn new<F, I>(field: F, documents: I) -> Self
+    where
+        F: Into<Field>,
+        I: IntoIterator<Item = Value>,
+    {
+        Self {
+            field: field.into(),
+            documents: documents.into_iter().collect(),
+        }
+    }
+
+    /// Creates a PercolateFilter by serializing the documents
+    pub fn from_documents<F, I, D>(field: F, documents: I) -> serde_json::Result<Self>
+    where
+        F: Into<Field>,
+        I: IntoIterator<Item = D>,
+        D: Serialize,
+    {
+        let documents = documents
+            .into_iter()
+            .map(serde_json::to_value)
+            .collect::<serde_json::Result<_>>()?;
+
+        Ok(Self {
+            field: field.into(),
+            documents,
+        })
+    }
+}
+
+impl CriterionData for PercolateFilter {}
+
+impl From<PercolateFilter> for Criterion {
+    fn from(value: PercolateFilter) -> Self {
+        Self::Percolate(value)
+    }
+}
+
+impl Serialize for PercolateFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        #[derive(Serialize)]
+        struct Params<'a> {
+            field: &'a Field,
+            documents: &'a Vec<Value>,
+        }
+
+        let params = Params {
+            field: &self.field,
+            documents: &self.documents,
+        };
+
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("percolate", &params)?;
+        map.end()
+    }
+}
+
+impl CollectFields for PercolateFilter {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        self.field.collect_fields(usage, fields);
+    }
+}
@@ -0,0 +1,281 @@
+use super::*;
+use serde::Serialize;
+use std::borrow::Cow;
+
+/// Query String
+///
+/// Searches with the Lucene query syntax, such as
+/// `title:(red OR blue) AND NOT used`, which is handy for letting
+/// power users type their own queries.  A syntax error fails the
+/// whole search, see [simple_query_string] for a forgiving syntax.
+///
+/// ```rust
+/// use elastic_lens::prelude::*;
+/// use elastic_lens::request::search::Operator;
+///
+/// let mut search = Search::default();
+///
+/// search.with(field("in_stock").contains(true));
+/// search.score_with(
+///     query_string("shirt AND (red OR blue)")
+///         .boosted_field("title", 2.0)
+///         .field("description")
+///         .default_operator(Operator::And)
+/// );
+/// ```
+///
+pub fn query_string<Q: Into<Cow<'static, str>>>(query: Q) -> QueryString {
+    QueryString::new(query)
+}
+
+/// Simple Query String
+///
+/// Searches with a limited syntax, such as `"red shirt" +cotton -used`,
+/// which ignores any invalid parts instead of failing.
+///
+pub fn simple_query_string<Q: Into<Cow<'static, str>>>(query: Q) -> SimpleQueryString {
+    SimpleQueryString::new(query)
+}
+
+/// Search by a query in the Lucene query syntax
+#[derive(Debug, Clone)]
+pub struct QueryString {
+    options: QueryStringOptions,
+}
+
+/// Search by a query in the simple query string syntax
+#[derive(Debug, Clone)]
+pub struct SimpleQueryString {
+    options: QueryStringOptions,
+    flags: Vec<SimpleQueryFlag>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct QueryStringOptions {
+    query: Cow<'static, str>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fields: Vec<BoostedField>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_operator: Option<Operator>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    analyzer: Option<Cow<'static, str>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lenient: Option<bool>,
+}
+
+/// Operators of the simple query string syntax
+/// which can be turned on with [SimpleQueryString::flags]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SimpleQueryFlag {
+    /// every operator, the default
+    All,
+    /// no operators at all
+    None,
+    /// `+` for and
+    And,
+    /// `|` for or
+    Or,
+    /// `-` for not
+    Not,
+    /// `*` at the end of a term for a prefix
+    Prefix,
+    /// `"` around terms for a phrase
+    Phrase,
+    /// `(` and `)` for grouping
+    Precedence,
+    /// `\` to escape characters
+    Escape,
+    /// whitespace splits terms
+    Whitespace,
+    /// `~N` after a term for fuzziness
+    Fuzzy,
+    /// `~N` after a phrase for slop
+    Near,
+}
+
+impl SimpleQueryFlag {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::All => "ALL",
+            Self::None => "NONE",
+            Self::And => "AND",
+            Self::Or => "OR",
+            Self::Not => "NOT",
+            Self::Prefix => "PREFIX",
+            Self::Phrase => "PHRASE",
+            Self::Precedence => "PRECEDENCE",
+            Self::Escape => "ESCAPE",
+            Self::Whitespace => "WHITESPACE",
+            Self::Fuzzy => "FUZZY",
+            Self::Near => "NEAR",
+        }
+    }
+}
+
+impl QueryStringOptions {
+    fn new(query: Cow<'static, str>) -> Self {
+        Self {
+            query,
+            fields: vec![],
+            default_operator: None,
+            analyzer: None,
+            lenient: None,
+        }
+    }
+}
+
+/// both query strings share the same options
+macro_rules! query_string_options {
+    () => {
+        /// Search this field, where without any fields the
+        /// default fields of the index are searched.
+        pub fn field<F: Into<Field>>(mut self, field: F) -> Self {
+            self.options.fields.push(BoostedField {
+                field: field.into(),
+                boost: None,
+            });
+            self
+        }
+
+        /// Search this field with matches on it counting
+        /// towards the score by the boost
+        pub fn boosted_field<F: Into<Field>>(mut self, field: F, boost: f64) -> Self {
+            self.options.fields.push(BoostedField {
+                field: field.into(),
+                boost: Some(boost),
+            });
+            self
+        }
+
+        /// how terms without an operator between them are combined
+        pub fn default_operator(mut self, operator: Operator) -> Self {
+            self.options.default_operator = Some(operator);
+            self
+        }
+
+        /// analyzer for the text of the query
+        pub fn analyzer<A: Into<Cow<'static, str>>>(mut self, analyzer: A) -> Self {
+            self.options.analyzer = Some(analyzer.into());
+            self
+        }
+
+        /// ignore values which don't fit the type of a field,
+        /// such as text searched against a number field
+        pub fn lenient(mut self, lenient: bool) -> Self {
+            self.options.lenient = Some(lenient);
+            self
+        }
+    };
+}
+
+impl QueryString {
+    /// Creates a QueryString
+    pub fn new<Q: Into<Cow<'static, str>>>(query: Q) -> Self {
+        Self {
+            options: QueryStringOptions::new(query.into()),
+        }
+    }
+
+    query_string_options!();
+}
+
+impl SimpleQueryString {
+    /// Creates a SimpleQueryString
+    pub fn new<Q: Into<Cow<'static, str>>>(query: Q) -> Self {
+        Self {
+            options: QueryStringOptions::new(query.into()),
+            flags: vec![],
+        }
+    }
+
+    query_string_options!();
+
+    /// only allow these operators in the query, where
+    /// any others are treated as plain text
+    pub fn flags<I: IntoIterator<Item = SimpleQueryFlag>>(mut self, flags: I) -> Self {
+        self.flags.extend(flags);
+        self
+    }
+}
+
+impl CriterionData for QueryString {}
+impl CriterionData for SimpleQueryString {}
+
+impl From<QueryString> for Criterion {
+    fn from(value: QueryString) -> Self {
+        Self::QueryString(value)
+    }
+}
+
+impl From<SimpleQueryString> for Criterion {
+    fn from(value: SimpleQueryString) -> Self {
+        Self::SimpleQueryString(value)
+    }
+}
+
+impl Serialize for QueryString {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("query_string", &self.options)?;
+        map.end()
+    }
+}
+
+impl Serialize for SimpleQueryString {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        #[derive(Serialize)]
+        struct Params<'a> {
+            #[serde(flatten)]
+            options: &'a QueryStringOptions,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            flags: Option<String>,
+        }
+
+        let flags = if self.flags.is_empty() {
+            None
+        } else {
+            let names: Vec<_> = self.flags.iter().map(SimpleQueryFlag::name).collect();
+            Some(names.join("|"))
+        };
+
+        let params = Params {
+            options: &self.options,
+            flags,
+        };
+
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("simple_query_string", &params)?;
+        map.end()
+    }
+}
+
+impl CollectFields for QueryString {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        for boosted in &self.options.fields {
+            boosted.field.collect_fields(usage, fields);
+        }
+    }
+}
+
+impl CollectFields for SimpleQueryString {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        for boosted in &self.options.fields {
+            boosted.field.collect_fields(usage, fields);
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use super::*;
 use serde::{ser::SerializeMap, Serialize};
+use std::borrow::Cow;
 use std::ops::Range;
 
 /// Range Field
@@ -12,6 +13,8 @@ pub struct RangeFilter {
     field: Field,
     upper_bound: Option<UpperBound>,
     lower_bound: Option<LowerBound>,
+    format: Option<Cow<'static, str>>,
+    time_zone: Option<Cow<'static, str>>,
 }
 
 /// Build a range criterion
@@ -20,6 +23,8 @@ pub struct RangeFilterBuilder {
     field: Field,
     upper_bound: Option<UpperBound>,
     lower_bound: Option<LowerBound>,
+    format: Option<Cow<'static, str>>,
+    time_zone: Option<Cow<'static, str>>,
 }
 
 impl RangeFilterBuilder {
@@ -30,6 +35,8 @@ impl RangeFilterBuilder {
             field: field.into(),
             upper_bound: None,
             lower_bound: None,
+            format: None,
+            time_zone: None,
         }
     }
 
@@ -67,12 +74,37 @@ impl RangeFilterBuilder {
         self
     }
 
+    /// Format
+    ///
+    /// How dates given to the range are formatted, such as
+    /// `yyyy-MM-dd`, when they don't match the format of the
+    /// field.  Any [DateMath] anchor uses this format as well.
+    ///
+    pub fn format<S: Into<Cow<'static, str>>>(mut self, format: S) -> Self {
+        self.format = Some(format.into());
+        self
+    }
+
+    /// Time Zone
+    ///
+    /// Dates given to the range without a time zone are in this
+    /// one, either an offset such as `-05:00` or a name such as
+    /// `America/New_York`.  This also moves the rounding of any
+    /// [DateMath] to the start of the day in this time zone.
+    ///
+    pub fn time_zone<S: Into<Cow<'static, str>>>(mut self, time_zone: S) -> Self {
+        self.time_zone = Some(time_zone.into());
+        self
+    }
+
     /// Creates RangeFilter
     pub fn build(self) -> RangeFilter {
         RangeFilter {
             field: self.field,
             upper_bound: self.upper_bound,
             lower_bound: self.lower_bound,
+            format: self.format,
+            time_zone: self.time_zone,
         }
     }
 }
@@ -151,6 +183,14 @@ impl Serialize for RangeFilter {
                     size += 1;
                 }
 
+                if self.range.format.is_some() {
+                    size += 1;
+                }
+
+                if self.range.time_zone.is_some() {
+                    size += 1;
+                }
+
                 let mut map = serializer.serialize_map(Some(size))?;
 
                 if let Some(range) = &self.range.lower_bound {
@@ -161,6 +201,14 @@ impl Serialize for RangeFilter {
                     range.apply_entry(&mut map)?;
                 }
 
+                if let Some(format) = &self.range.format {
+                    map.serialize_entry("format", format)?;
+                }
+
+                if let Some(time_zone) = &self.range.time_zone {
+                    map.serialize_entry("time_zone", time_zone)?;
+                }
+
                 map.end()
             }
         }
@@ -187,3 +235,9 @@ impl Serialize for RangeFilter {
         map.end()
     }
 }
+
+impl CollectFields for RangeFilter {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        self.field.collect_fields(usage, fields);
+    }
+}
@@ -0,0 +1,136 @@
+use super::*;
+
+/// Scoring Criteria
+///
+/// Criteria which count towards the relevance score of a
+/// document, where the criteria added with
+/// [CriteriaBuilder::with] only select documents.  Required
+/// criteria are placed in `must` and optional ones in `should`.
+///
+#[derive(Debug, Clone, Default)]
+pub struct ScoringCriteria {
+    must: Vec<Criterion>,
+    should: Vec<Criterion>,
+    minimum_should_match: Option<ScalarValue>,
+    boost: Option<f64>,
+}
+
+impl ScoringCriteria {
+    /// criteria which must select and add to the score
+    pub fn must(&self) -> Option<&Vec<Criterion>> {
+        if self.must.is_empty() {
+            None
+        } else {
+            Some(&self.must)
+        }
+    }
+
+    /// criteria which add to the score when they select
+    pub fn should(&self) -> Option<&Vec<Criterion>> {
+        if self.should.is_empty() {
+            None
+        } else {
+            Some(&self.should)
+        }
+    }
+
+    /// how many of the should criteria must select
+    pub fn minimum_should_match(&self) -> Option<&ScalarValue> {
+        self.minimum_should_match.as_ref()
+    }
+
+    /// multiplier applied to the score
+    pub fn boost(&self) -> Option<f64> {
+        self.boost
+    }
+
+    /// the boost and minimum are kept even without any scoring
+    /// criteria, as they still apply to the filters beside them
+    pub(crate) fn has_data(&self) -> bool {
+        !(self.must.is_empty() && self.should.is_empty())
+            || self.minimum_should_match.is_some()
+            || self.boost.is_some()
+    }
+}
+
+/// Scoring Builder
+///
+/// Adds criteria which contribute to the relevance score, such
+/// as full text matches, alongside the criteria from
+/// [CriteriaBuilder::with] which only select.
+///
+/// ```rust
+/// use elastic_lens::prelude::*;
+///
+/// let mut search = Search::default();
+///
+/// // only selects, doesn't effect the score
+/// search.with(field("in_stock").contains(true));
+///
+/// // must match and ranks by how well it does
+/// search.score_with(field("title").matches("red shirt"));
+///
+/// // ranks higher if it matches, but doesn't need to
+/// search.should_score_with(field("brand").contains("acme"));
+/// ```
+///
+pub trait ScoringBuilder: CriteriaBuilder {
+    /// Should return a mutable reference to the scoring criteria
+    fn scoring_criteria_mut(&mut self) -> &mut ScoringCriteria;
+
+    /// Having the provided condition, which adds to the score.  A
+    /// negated condition excludes the same as it would with
+    /// [CriteriaBuilder::with] as exclusions don't score.
+    fn score_with<SC: Into<SearchCondition>>(&mut self, condition: SC) {
+        let condition = condition.into();
+
+        if !condition.criterion.is_usable() {
+            return;
+        }
+
+        match condition.tag {
+            Tag::Positive => self.scoring_criteria_mut().must.push(condition.criterion),
+            Tag::Negative => self.negative_criteria_mut().push(condition.criterion),
+        }
+    }
+
+    /// Should Score With
+    ///
+    /// Optionally having the provided condition, which adds to the
+    /// score when it matches.  If there is nothing else selecting
+    /// documents at least one of these must match, otherwise none
+    /// of them have to unless a minimum is set.
+    ///
+    fn should_score_with<SC: Into<SearchCondition>>(&mut self, condition: SC) {
+        let condition = condition.into();
+
+        if !condition.criterion.is_usable() {
+            return;
+        }
+
+        let criterion = match condition.tag {
+            Tag::Positive => condition.criterion,
+            Tag::Negative => NotAll::single(condition.criterion).into(),
+        };
+
+        self.scoring_criteria_mut().should.push(criterion);
+    }
+
+    /// how many of the should criteria must match, which is either
+    /// a count such as `2` or a percentage such as `"75%"`
+    fn set_minimum_should_match<V: Into<ScalarValue>>(&mut self, minimum: V) {
+        self.scoring_criteria_mut().minimum_should_match = Some(minimum.into());
+    }
+
+    /// multiplier applied to the score of these criteria
+    fn set_boost(&mut self, boost: f64) {
+        self.scoring_criteria_mut().boost = Some(boost);
+    }
+}
+
+impl CollectFields for ScoringCriteria {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        self.must.collect_fields(usage, fields);
+        self.should.collect_fields(usage, fields);
+    }
+}
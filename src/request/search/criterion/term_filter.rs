@@ -8,6 +8,7 @@ use serde::Serialize;
 pub struct TermFilter {
     field: Field,
     value: ValueType,
+    case_insensitive: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -35,6 +36,20 @@ impl TermFilter {
         Self {
             field: field.into(),
             value: ValueType::Single(value.into()),
+            case_insensitive: false,
+        }
+    }
+
+    /// create a term filter which ignores the case of the value,
+    /// something only a single value term filter supports
+    pub fn single_ignoring_case<F, V>(field: F, value: V) -> Self
+    where
+        F: Into<Field>,
+        V: Into<ScalarValue>,
+    {
+        Self {
+            case_insensitive: true,
+            ..Self::single(field, value)
         }
     }
 
@@ -49,6 +64,7 @@ impl TermFilter {
         Self {
             field: field.into(),
             value: ValueType::Many(values),
+            case_insensitive: false,
         }
     }
 }
@@ -80,6 +96,12 @@ impl Serialize for TermFilter {
             }
         }
 
+        #[derive(Serialize)]
+        struct CaseInsensitive<'a> {
+            value: &'a ValueType,
+            case_insensitive: bool,
+        }
+
         struct FieldValuePair<'a> {
             pair: &'a TermFilter,
         }
@@ -90,7 +112,15 @@ impl Serialize for TermFilter {
                 S: serde::Serializer,
             {
                 let mut map = serializer.serialize_map(Some(1))?;
-                map.serialize_entry(&self.pair.field, &self.pair.value)?;
+                if self.pair.case_insensitive {
+                    let value = CaseInsensitive {
+                        value: &self.pair.value,
+                        case_insensitive: true,
+                    };
+                    map.serialize_entry(&self.pair.field, &value)?;
+                } else {
+                    map.serialize_entry(&self.pair.field, &self.pair.value)?;
+                }
                 map.end()
             }
         }
@@ -101,3 +131,9 @@ impl Serialize for TermFilter {
         map.end()
     }
 }
+
+impl CollectFields for TermFilter {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        self.field.collect_fields(usage, fields);
+    }
+}
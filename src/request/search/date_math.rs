@@ -0,0 +1,143 @@
+use super::ScalarValue;
+use std::fmt;
+
+/// Date Math
+///
+/// A date relative to now or to an anchor date, which Elasticsearch
+/// works out when the search runs.  Can be used anywhere a
+/// [ScalarValue] is expected, such as the bounds of a range.
+///
+/// ```rust
+/// use elastic_lens::prelude::*;
+/// use elastic_lens::request::search::{DateMath, DateUnit};
+///
+/// // now-7d/d
+/// let week_ago = DateMath::now().minus(7, DateUnit::Days).rounded_to(DateUnit::Days);
+///
+/// // 2024-01-31||+1M/d
+/// let next_month = DateMath::anchor("2024-01-31")
+///     .plus(1, DateUnit::Months)
+///     .rounded_to(DateUnit::Days);
+///
+/// let mut search = Search::default();
+/// search.with(field("created_at").greater_than_or_equal(week_ago));
+/// ```
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct DateMath {
+    anchor: Option<ScalarValue>,
+    // wider than the amounts given so negating `i64::MIN` fits
+    operations: Vec<(i128, DateUnit)>,
+    rounding: Option<DateUnit>,
+}
+
+/// Units of time used by [DateMath]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateUnit {
+    /// `y`
+    Years,
+    /// `M`
+    Months,
+    /// `w`
+    Weeks,
+    /// `d`
+    Days,
+    /// `h`
+    Hours,
+    /// `m`
+    Minutes,
+    /// `s`
+    Seconds,
+}
+
+impl DateMath {
+    /// Relative to the time the search is ran
+    pub fn now() -> Self {
+        Self {
+            anchor: None,
+            operations: vec![],
+            rounding: None,
+        }
+    }
+
+    /// Anchor
+    ///
+    /// Relative to a date, which is formatted the same as the
+    /// field or the `format` of the range it is used in.  With the
+    /// `chrono` or `time` features their dates can be used as well.
+    ///
+    pub fn anchor<A: Into<ScalarValue>>(date: A) -> Self {
+        Self {
+            anchor: Some(date.into()),
+            ..Self::now()
+        }
+    }
+
+    /// add an amount of time
+    pub fn plus(mut self, amount: i64, unit: DateUnit) -> Self {
+        self.operations.push((amount.into(), unit));
+        self
+    }
+
+    /// subtract an amount of time
+    pub fn minus(mut self, amount: i64, unit: DateUnit) -> Self {
+        self.operations.push((-i128::from(amount), unit));
+        self
+    }
+
+    /// Rounded To
+    ///
+    /// Rounds the date to the unit after any math is done, such
+    /// as the start of the day.  In a range it rounds up for `gt`
+    /// and `lte` and down for `gte` and `lt`, so whole units are
+    /// included or excluded.
+    ///
+    pub fn rounded_to(mut self, unit: DateUnit) -> Self {
+        self.rounding = Some(unit);
+        self
+    }
+}
+
+impl DateUnit {
+    /// the letter Elasticsearch uses for the unit
+    pub fn symbol(&self) -> char {
+        match self {
+            Self::Years => 'y',
+            Self::Months => 'M',
+            Self::Weeks => 'w',
+            Self::Days => 'd',
+            Self::Hours => 'h',
+            Self::Minutes => 'm',
+            Self::Seconds => 's',
+        }
+    }
+}
+
+/// Formats as Elasticsearch expects, such as `now-7d/d`
+impl fmt::Display for DateMath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.anchor {
+            None => f.write_str("now")?,
+            Some(ScalarValue::Text(text)) => write!(f, "{text}||")?,
+            Some(ScalarValue::Integer(int)) => write!(f, "{int}||")?,
+            Some(ScalarValue::Float(float)) => write!(f, "{float}||")?,
+            Some(ScalarValue::Boolean(boolean)) => write!(f, "{boolean}||")?,
+        }
+
+        for (amount, unit) in &self.operations {
+            write!(f, "{amount:+}{}", unit.symbol())?;
+        }
+
+        if let Some(unit) = self.rounding {
+            write!(f, "/{}", unit.symbol())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<DateMath> for ScalarValue {
+    fn from(value: DateMath) -> Self {
+        Self::Text(value.to_string().into())
+    }
+}
@@ -0,0 +1,98 @@
+use super::*;
+
+/// How a search makes use of a field
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldUsage {
+    /// selects or excludes documents
+    Query,
+
+    /// orders the results
+    Sort,
+
+    /// groups or summarizes documents
+    Aggregation,
+
+    /// collapses results down to one per value
+    Collapse,
+}
+
+impl FieldUsage {
+    /// Sorting, collapsing, and aggregating all need the
+    /// field to be aggregatable ( have doc values )
+    pub fn needs_aggregatable(&self) -> bool {
+        !matches!(self, Self::Query)
+    }
+}
+
+/// A field referenced somewhere in a search
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldReference<'a> {
+    /// the field being referenced
+    pub field: &'a Field,
+
+    /// how the field is used
+    pub usage: FieldUsage,
+}
+
+/// Every field referenced by the criteria, sorts,
+/// aggregations, and collapse of a search.  A field
+/// is listed once for each place it's referenced.
+pub fn referenced_fields<S: SearchTrait>(search: &S) -> Vec<FieldReference<'_>> {
+    let mut fields = vec![];
+
+    for criteria in [search.positive_criteria(), search.negative_criteria()]
+        .into_iter()
+        .flatten()
+    {
+        criteria.collect_fields(FieldUsage::Query, &mut fields);
+    }
+
+    if let Some(scoring) = search.scoring_criteria() {
+        scoring.collect_fields(FieldUsage::Query, &mut fields);
+    }
+
+    if let Some(sorts) = search.sort_directives() {
+        for sort in sorts {
+            sort.collect_fields(FieldUsage::Sort, &mut fields);
+        }
+    }
+
+    if let Some(aggs) = search.aggregations() {
+        aggs.collect_fields(FieldUsage::Aggregation, &mut fields);
+    }
+
+    if let Some(collapse) = search.collapse_by() {
+        collapse.collect_fields(FieldUsage::Collapse, &mut fields);
+    }
+
+    fields
+}
+
+/// Implemented by every part of a search which can reference
+/// a field.  The usage is how the part is being used, which is
+/// handed down to anything it's made up of.
+pub(crate) trait CollectFields {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>);
+}
+
+impl CollectFields for Field {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        fields.push(FieldReference { field: self, usage });
+    }
+}
+
+impl<T: CollectFields> CollectFields for Vec<T> {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        for item in self {
+            item.collect_fields(usage, fields);
+        }
+    }
+}
+
+impl<T: CollectFields> CollectFields for Option<T> {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        if let Some(item) = self {
+            item.collect_fields(usage, fields);
+        }
+    }
+}
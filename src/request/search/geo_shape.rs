@@ -0,0 +1,248 @@
+use super::{GeoPoint, IntoGeoPoint};
+use serde::de::{self, Deserializer};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Serialize};
+
+/// Geo Shape
+///
+/// An area on the map to compare geo fields against with a
+/// [crate::request::search::GeoShapeFilter].  Shapes serialize to
+/// and from GeoJSON geometry, so they can be read from or handed
+/// to anything else which speaks it.
+///
+/// ```rust
+/// use elastic_lens::request::search::{GeoPoint, GeoShape};
+///
+/// let downtown = GeoShape::polygon([
+///     GeoPoint::new(40.73, -74.1),
+///     GeoPoint::new(40.73, -73.9),
+///     GeoPoint::new(40.01, -73.9),
+/// ]);
+/// ```
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum GeoShape {
+    /// a single point
+    Point(GeoPoint),
+
+    /// a rectangle by it's top left and bottom right corners
+    Envelope {
+        /// north west corner
+        top_left: GeoPoint,
+        /// south east corner
+        bottom_right: GeoPoint,
+    },
+
+    /// an outer ring followed by any holes cut out of it
+    Polygon(Vec<Vec<GeoPoint>>),
+
+    /// several polygons treated as one shape
+    MultiPolygon(Vec<Vec<Vec<GeoPoint>>>),
+}
+
+/// How a geo field is compared to a [GeoShape]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GeoShapeRelation {
+    /// the field and shape overlap at all, the default
+    Intersects,
+
+    /// the field is entirely inside of the shape
+    Within,
+
+    /// the field and shape don't overlap at all
+    Disjoint,
+
+    /// the field entirely covers the shape
+    Contains,
+}
+
+/// Problems building a [GeoShape]
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum GeoShapeError {
+    /// a shape given to [GeoShape::multi_polygon] by
+    /// it's position which isn't a polygon
+    #[error("shape {0} of the multi polygon isn't a polygon")]
+    NotAPolygon(usize),
+}
+
+impl GeoShape {
+    /// A rectangle by it's top left and bottom right corners
+    pub fn envelope<T: IntoGeoPoint, B: IntoGeoPoint>(top_left: T, bottom_right: B) -> Self {
+        Self::Envelope {
+            top_left: top_left.into_geo_point(),
+            bottom_right: bottom_right.into_geo_point(),
+        }
+    }
+
+    /// A polygon by the points of it's outline, which
+    /// is closed for you if the last point isn't the first
+    pub fn polygon<I, P>(points: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: IntoGeoPoint,
+    {
+        Self::Polygon(vec![ring(points)])
+    }
+
+    /// A polygon with holes cut out of it
+    pub fn polygon_with_holes<I, P, H, HP>(points: I, holes: H) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: IntoGeoPoint,
+        H: IntoIterator<Item = HP>,
+        HP: IntoIterator<Item = P>,
+    {
+        let mut rings = vec![ring(points)];
+        rings.extend(holes.into_iter().map(ring));
+        Self::Polygon(rings)
+    }
+
+    /// Several polygons treated as one shape, which fails
+    /// if any of the shapes given isn't a polygon
+    pub fn multi_polygon<I: IntoIterator<Item = GeoShape>>(
+        polygons: I,
+    ) -> Result<Self, GeoShapeError> {
+        let polygons = polygons
+            .into_iter()
+            .enumerate()
+            .map(|(index, shape)| match shape {
+                Self::Polygon(rings) => Ok(rings),
+                _ => Err(GeoShapeError::NotAPolygon(index)),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self::MultiPolygon(polygons))
+    }
+}
+
+fn ring<I, P>(points: I) -> Vec<GeoPoint>
+where
+    I: IntoIterator<Item = P>,
+    P: IntoGeoPoint,
+{
+    let mut points: Vec<_> = points
+        .into_iter()
+        .map(IntoGeoPoint::into_geo_point)
+        .collect();
+
+    if let (Some(first), Some(last)) = (points.first(), points.last()) {
+        if first != last {
+            points.push(*first);
+        }
+    }
+
+    points
+}
+
+/// GeoJSON positions are longitude first
+struct Position(GeoPoint);
+
+impl Serialize for Position {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        [self.0.lon, self.0.lat].serialize(serializer)
+    }
+}
+
+fn positions(points: &[GeoPoint]) -> Vec<Position> {
+    points.iter().copied().map(Position).collect()
+}
+
+impl Serialize for GeoShape {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(2))?;
+
+        match self {
+            Self::Point(point) => {
+                map.serialize_entry("type", "Point")?;
+                map.serialize_entry("coordinates", &Position(*point))?;
+            }
+            Self::Envelope {
+                top_left,
+                bottom_right,
+            } => {
+                map.serialize_entry("type", "envelope")?;
+                map.serialize_entry(
+                    "coordinates",
+                    &[Position(*top_left), Position(*bottom_right)],
+                )?;
+            }
+            Self::Polygon(rings) => {
+                let rings: Vec<_> = rings.iter().map(|ring| positions(ring)).collect();
+                map.serialize_entry("type", "Polygon")?;
+                map.serialize_entry("coordinates", &rings)?;
+            }
+            Self::MultiPolygon(polygons) => {
+                let polygons: Vec<Vec<_>> = polygons
+                    .iter()
+                    .map(|rings| rings.iter().map(|ring| positions(ring)).collect())
+                    .collect();
+                map.serialize_entry("type", "MultiPolygon")?;
+                map.serialize_entry("coordinates", &polygons)?;
+            }
+        }
+
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for GeoShape {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Geometry {
+            #[serde(rename = "type")]
+            kind: String,
+            coordinates: serde_json::Value,
+        }
+
+        fn parse<'de, T: Deserialize<'de>, E: de::Error>(
+            value: &'de serde_json::Value,
+        ) -> Result<T, E> {
+            T::deserialize(value).map_err(E::custom)
+        }
+
+        fn point([lon, lat]: [f64; 2]) -> GeoPoint {
+            GeoPoint { lat, lon }
+        }
+
+        fn rings(rings: Vec<Vec<[f64; 2]>>) -> Vec<Vec<GeoPoint>> {
+            rings
+                .into_iter()
+                .map(|ring| ring.into_iter().map(point).collect())
+                .collect()
+        }
+
+        let geometry = Geometry::deserialize(deserializer)?;
+        let coordinates = &geometry.coordinates;
+
+        match geometry.kind.to_ascii_lowercase().as_str() {
+            "point" => Ok(Self::Point(point(parse(coordinates)?))),
+            "envelope" => {
+                let [top_left, bottom_right]: [[f64; 2]; 2] = parse(coordinates)?;
+                Ok(Self::Envelope {
+                    top_left: point(top_left),
+                    bottom_right: point(bottom_right),
+                })
+            }
+            "polygon" => Ok(Self::Polygon(rings(parse(coordinates)?))),
+            "multipolygon" => {
+                let polygons: Vec<Vec<Vec<[f64; 2]>>> = parse(coordinates)?;
+                Ok(Self::MultiPolygon(
+                    polygons.into_iter().map(rings).collect(),
+                ))
+            }
+            other => Err(de::Error::custom(format!(
+                "unsupported geometry type {other}"
+            ))),
+        }
+    }
+}
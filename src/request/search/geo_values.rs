@@ -1,4 +1,7 @@
-use serde::Serialize;
+use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
 
 /// Represents Latitude and Longitude Coordinates
 ///
@@ -7,6 +10,22 @@ use serde::Serialize;
 /// Implement this trait in your codebase for your
 /// foreign types if you plan to use them directly
 /// for operations that require a `GeoPoint`.
+///
+/// Points can also be parsed from the other formats
+/// Elasticsearch accepts, all of which are checked to
+/// be within range:
+///
+/// ```rust
+/// use elastic_lens::request::search::GeoPoint;
+///
+/// let point: GeoPoint = "41.12,-71.34".parse().unwrap();
+/// let point: GeoPoint = "POINT (-71.34 41.12)".parse().unwrap();
+/// let point: GeoPoint = "drm3btev3e86".parse().unwrap();
+/// let point = GeoPoint::try_from([-71.34, 41.12]).unwrap();
+///
+/// assert!("91.0,0.0".parse::<GeoPoint>().is_err());
+/// ```
+///
 #[derive(Debug, Copy, Clone, PartialEq, Serialize, Default)]
 pub struct GeoPoint {
     /// The latitude is specified by degrees, starting from 0
@@ -21,6 +40,22 @@ pub struct GeoPoint {
     pub lon: f64,
 }
 
+/// Problems turning a value into a [GeoPoint]
+#[derive(Debug, thiserror::Error, Clone, PartialEq)]
+pub enum GeoPointError {
+    /// latitude outside of -90 to 90
+    #[error("latitude {0} is outside of -90 to 90")]
+    LatitudeOutOfRange(f64),
+
+    /// longitude outside of -180 to 180
+    #[error("longitude {0} is outside of -180 to 180")]
+    LongitudeOutOfRange(f64),
+
+    /// text which isn't any of the supported formats
+    #[error("unrecognized geo point format: {0}")]
+    InvalidFormat(String),
+}
+
 impl GeoPoint {
     /// Create a new Geopoint by lat/lon
     pub fn new<T, V>(lat: T, lon: V) -> Self
@@ -33,6 +68,139 @@ impl GeoPoint {
             lon: lon.into(),
         }
     }
+
+    /// Create a new Geopoint by lat/lon, checking that
+    /// both are within range
+    pub fn try_new<T, V>(lat: T, lon: V) -> Result<Self, GeoPointError>
+    where
+        T: Into<f64>,
+        V: Into<f64>,
+    {
+        Self::new(lat, lon).validate()
+    }
+
+    /// Geohash
+    ///
+    /// Decodes a geohash such as `drm3btev3e86` to the center of
+    /// the cell it covers.  Longer hashes are more precise.
+    ///
+    pub fn from_geohash(hash: &str) -> Result<Self, GeoPointError> {
+        const ALPHABET: &[u8] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+        let invalid = || GeoPointError::InvalidFormat(hash.to_owned());
+
+        if hash.is_empty() {
+            return Err(invalid());
+        }
+
+        let mut lat = (-90.0, 90.0);
+        let mut lon = (-180.0, 180.0);
+        let mut is_lon = true;
+
+        for byte in hash.bytes() {
+            let bits = ALPHABET
+                .iter()
+                .position(|c| *c == byte.to_ascii_lowercase())
+                .ok_or_else(invalid)?;
+
+            for shift in (0..5).rev() {
+                let range: &mut (f64, f64) = if is_lon { &mut lon } else { &mut lat };
+                let mid = (range.0 + range.1) / 2.0;
+
+                if bits & (1 << shift) == 0 {
+                    range.1 = mid;
+                } else {
+                    range.0 = mid;
+                }
+
+                is_lon = !is_lon;
+            }
+        }
+
+        Ok(Self {
+            lat: (lat.0 + lat.1) / 2.0,
+            lon: (lon.0 + lon.1) / 2.0,
+        })
+    }
+
+    fn validate(self) -> Result<Self, GeoPointError> {
+        if !(-90.0..=90.0).contains(&self.lat) {
+            Err(GeoPointError::LatitudeOutOfRange(self.lat))
+        } else if !(-180.0..=180.0).contains(&self.lon) {
+            Err(GeoPointError::LongitudeOutOfRange(self.lon))
+        } else {
+            Ok(self)
+        }
+    }
+}
+
+/// Parses `"lat,lon"`, WKT such as `"POINT (lon lat)"`, or a geohash
+impl FromStr for GeoPoint {
+    type Err = GeoPointError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || GeoPointError::InvalidFormat(value.to_owned());
+        let number = |text: &str| text.trim().parse::<f64>().map_err(|_| invalid());
+        let trimmed = value.trim();
+
+        if let Some((lat, lon)) = trimmed.split_once(',') {
+            return Self::try_new(number(lat)?, number(lon)?);
+        }
+
+        let wkt = trimmed
+            .get(..5)
+            .filter(|prefix| prefix.eq_ignore_ascii_case("point"))
+            .map(|_| &trimmed[5..]);
+
+        if let Some(wkt) = wkt {
+            let coordinates = wkt
+                .trim()
+                .strip_prefix('(')
+                .and_then(|rest| rest.strip_suffix(')'))
+                .ok_or_else(invalid)?;
+
+            let mut parts = coordinates.split_whitespace();
+
+            return match (parts.next(), parts.next(), parts.next()) {
+                (Some(lon), Some(lat), None) => Self::try_new(number(lat)?, number(lon)?),
+                _ => Err(invalid()),
+            };
+        }
+
+        Self::from_geohash(trimmed)
+    }
+}
+
+/// GeoJSON order of `[lon, lat]`
+impl TryFrom<[f64; 2]> for GeoPoint {
+    type Error = GeoPointError;
+
+    fn try_from([lon, lat]: [f64; 2]) -> Result<Self, Self::Error> {
+        Self::try_new(lat, lon)
+    }
+}
+
+impl<'de> Deserialize<'de> for GeoPoint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Format {
+            Object { lat: f64, lon: f64 },
+            Array([f64; 2]),
+            Text(String),
+        }
+
+        let point = match Format::deserialize(deserializer)? {
+            Format::Object { lat, lon } => GeoPoint::try_new(lat, lon),
+            Format::Array(position) => GeoPoint::try_from(position),
+            Format::Text(text) => text.parse(),
+        };
+
+        point.map_err(de::Error::custom)
+    }
 }
 
 /// Implement this for any foreign type you have
@@ -48,14 +216,130 @@ impl IntoGeoPoint for GeoPoint {
     }
 }
 
-/// When expressing a distance, it can be in either
-/// miles or kilomenters; whichever you pick is fine
-/// but Elasticsearch needs to know.
-#[derive(Debug, Copy, Clone)]
+/// When expressing a distance, it can be in any of the
+/// units Elasticsearch understands; whichever you pick
+/// is fine but Elasticsearch needs to know.
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum Distance {
     /// distance measurement by the royal standard; god save the king
     Miles(usize),
 
     /// Modern standard unit of distance measurement
     Kilometers(usize),
+
+    /// any amount, including fractions, in any of the
+    /// units, best created with [Distance::new]
+    Measured(f64, DistanceUnit),
+}
+
+/// Units a [Distance] can be measured in, also used
+/// to pick what unit sorted distances are reported in
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+pub enum DistanceUnit {
+    /// `mi`
+    #[serde(rename = "mi")]
+    Miles,
+
+    /// `km`
+    #[serde(rename = "km")]
+    Kilometers,
+
+    /// `m`
+    #[serde(rename = "m")]
+    Meters,
+
+    /// `yd`
+    #[serde(rename = "yd")]
+    Yards,
+
+    /// `ft`
+    #[serde(rename = "ft")]
+    Feet,
+
+    /// `nmi`
+    #[serde(rename = "nmi")]
+    NauticalMiles,
+}
+
+/// Problems creating a [Distance]
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq)]
+pub enum DistanceError {
+    /// amount which is negative, infinite, or not a number
+    #[error("distance {0} isn't a finite amount of zero or more")]
+    InvalidAmount(f64),
+}
+
+impl Distance {
+    /// Create a distance of the amount in the unit, checking
+    /// that it's a finite amount which isn't negative
+    pub fn new<A: Into<f64>>(amount: A, unit: DistanceUnit) -> Result<Self, DistanceError> {
+        Self::Measured(amount.into(), unit).validate()
+    }
+
+    /// a whole number of units, kept as miles or
+    /// kilometers when it's in either of them
+    pub(crate) fn whole(amount: usize, unit: DistanceUnit) -> Self {
+        match unit {
+            DistanceUnit::Miles => Self::Miles(amount),
+            DistanceUnit::Kilometers => Self::Kilometers(amount),
+            unit => Self::Measured(amount as f64, unit),
+        }
+    }
+
+    /// how many units the distance is
+    pub fn amount(&self) -> f64 {
+        match *self {
+            Self::Miles(amount) | Self::Kilometers(amount) => amount as f64,
+            Self::Measured(amount, _) => amount,
+        }
+    }
+
+    /// the unit the distance is measured in
+    pub fn unit(&self) -> DistanceUnit {
+        match *self {
+            Self::Miles(_) => DistanceUnit::Miles,
+            Self::Kilometers(_) => DistanceUnit::Kilometers,
+            Self::Measured(_, unit) => unit,
+        }
+    }
+
+    pub(crate) fn validate(self) -> Result<Self, DistanceError> {
+        let amount = self.amount();
+
+        if amount.is_finite() && amount >= 0.0 {
+            Ok(self)
+        } else {
+            Err(DistanceError::InvalidAmount(amount))
+        }
+    }
+}
+
+impl DistanceUnit {
+    /// the suffix Elasticsearch uses for the unit
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            Self::Miles => "mi",
+            Self::Kilometers => "km",
+            Self::Meters => "m",
+            Self::Yards => "yd",
+            Self::Feet => "ft",
+            Self::NauticalMiles => "nmi",
+        }
+    }
+}
+
+/// Formats as Elasticsearch expects, such as `0.5mi`
+impl fmt::Display for Distance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.amount(), self.unit().suffix())
+    }
+}
+
+impl Serialize for Distance {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
 }
@@ -1,4 +1,4 @@
-use super::NumericValue;
+use super::{Distance, NumericValue};
 use serde::Serialize;
 use std::borrow::Cow;
 
@@ -105,6 +105,12 @@ impl From<NumericValue> for ScalarValue {
     }
 }
 
+impl From<Distance> for ScalarValue {
+    fn from(value: Distance) -> Self {
+        Self::Text(value.to_string().into())
+    }
+}
+
 impl Serialize for ScalarValue {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -118,3 +124,81 @@ impl Serialize for ScalarValue {
         }
     }
 }
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for ScalarValue {
+    fn from(value: chrono::DateTime<Tz>) -> Self {
+        Self::Text(
+            value
+                .to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true)
+                .into(),
+        )
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDateTime> for ScalarValue {
+    fn from(value: chrono::NaiveDateTime) -> Self {
+        Self::Text(value.format("%Y-%m-%dT%H:%M:%S%.f").to_string().into())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDate> for ScalarValue {
+    fn from(value: chrono::NaiveDate) -> Self {
+        Self::Text(value.format("%Y-%m-%d").to_string().into())
+    }
+}
+
+/// years outside of what RFC 3339 allows are sent as epoch millis
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for ScalarValue {
+    fn from(value: time::OffsetDateTime) -> Self {
+        if !(0..=9999).contains(&value.year()) {
+            return Self::Integer((value.unix_timestamp_nanos() / 1_000_000) as i64);
+        }
+
+        let offset = value.offset();
+        let zone = if offset.is_utc() {
+            "Z".to_owned()
+        } else {
+            let (hours, minutes, _) = offset.as_hms();
+            let sign = if offset.is_negative() { '-' } else { '+' };
+            format!("{sign}{:02}:{:02}", hours.abs(), minutes.abs())
+        };
+
+        Self::Text(format!("{}T{}{zone}", value.date(), time_of_day(value.time())).into())
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::PrimitiveDateTime> for ScalarValue {
+    fn from(value: time::PrimitiveDateTime) -> Self {
+        Self::Text(format!("{}T{}", value.date(), time_of_day(value.time())).into())
+    }
+}
+
+/// `hh:mm:ss` with as many fractional digits as needed,
+/// in steps of three, the same as chrono's `%.f`
+#[cfg(feature = "time")]
+fn time_of_day(time: time::Time) -> String {
+    let (hour, minute, second, nanos) = time.as_hms_nano();
+    let whole = format!("{hour:02}:{minute:02}:{second:02}");
+
+    if nanos == 0 {
+        whole
+    } else if nanos % 1_000_000 == 0 {
+        format!("{whole}.{:03}", nanos / 1_000_000)
+    } else if nanos % 1_000 == 0 {
+        format!("{whole}.{:06}", nanos / 1_000)
+    } else {
+        format!("{whole}.{nanos:09}")
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::Date> for ScalarValue {
+    fn from(value: time::Date) -> Self {
+        Self::Text(value.to_string().into())
+    }
+}
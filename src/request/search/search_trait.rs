@@ -27,6 +27,11 @@ pub trait SearchTrait {
         None
     }
 
+    /// the criteria that will select documents and add to their score
+    fn scoring_criteria(&self) -> Option<&ScoringCriteria> {
+        None
+    }
+
     /// any aggregations to request from Elasticsearch
     fn aggregations(&self) -> Option<&AggCollection> {
         None
@@ -42,6 +47,26 @@ pub trait SearchTrait {
         None
     }
 
+    /// should the total hit count be tracked, None will use the default
+    fn track_total_hits(&self) -> Option<bool> {
+        None
+    }
+
+    /// should each hit explain how its score was computed
+    fn explain(&self) -> Option<bool> {
+        None
+    }
+
+    /// should the response detail where time was spent
+    fn profile(&self) -> Option<bool> {
+        None
+    }
+
+    /// can the response be served from and stored in the client's cache
+    fn cacheable(&self) -> bool {
+        true
+    }
+
     /// Produces a structure that can be serialized into the body
     /// request for Elasticsearch.  This is a borrow from the trait
     /// and therefore locks modification while the body is around.
@@ -51,4 +76,14 @@ pub trait SearchTrait {
     {
         SearchBody::from(self)
     }
+
+    /// Only the query of the search, ready to be stored in a
+    /// document's `percolator` field so that it can later be
+    /// matched against documents with [PercolateFilter].
+    fn percolator_query(&self) -> PercolatorQuery<'_>
+    where
+        Self: Sized,
+    {
+        SearchBody::from(self).into_percolator()
+    }
 }
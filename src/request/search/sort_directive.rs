@@ -9,6 +9,7 @@ mod missing_value;
 mod script_params;
 mod script_score;
 mod script_sort;
+mod script_source;
 
 pub use builder_trait::*;
 pub use direction::*;
@@ -18,6 +19,7 @@ pub use missing_value::*;
 pub use script_params::*;
 pub use script_score::*;
 pub use script_sort::*;
+pub use script_source::*;
 
 /// Describes a way to sort documents from a search
 #[derive(Debug, Clone)]
@@ -45,6 +47,16 @@ impl SortDirective {
     }
 }
 
+impl CollectFields for SortDirective {
+    fn collect_fields<'a>(&'a self, usage: FieldUsage, fields: &mut Vec<FieldReference<'a>>) {
+        match self {
+            Self::Field(params) => params.field.collect_fields(usage, fields),
+            Self::GeoDistance(params) => params.field.collect_fields(usage, fields),
+            Self::ScriptScore(_) | Self::ScriptSort(_) => {}
+        }
+    }
+}
+
 impl Serialize for SortDirective {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -31,6 +31,16 @@ pub fn by_script<S: Into<Cow<'static, str>>>(script: S) -> ScriptSortBuilder {
     ScriptSortBuilder::new(script)
 }
 
+/// Use to sort by a script-score stored on the server
+pub fn by_stored_script_score<S: Into<Cow<'static, str>>>(id: S) -> ScriptScoreBuilder {
+    ScriptScoreBuilder::new(ScriptSource::stored(id))
+}
+
+/// Use to sort by a script stored on the server
+pub fn by_stored_script<S: Into<Cow<'static, str>>>(id: S) -> ScriptSortBuilder {
+    ScriptSortBuilder::new(ScriptSource::stored(id))
+}
+
 /// Trait used to provide self-sort construction
 pub trait SortBuilderTrait {
     /// mutable reference to sorts you want to build on
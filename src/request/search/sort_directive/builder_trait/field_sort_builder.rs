@@ -18,6 +18,7 @@ impl FieldSortBuilder {
             order: None,
             ignore_unmapped: None,
             calc_formula: None,
+            unit: None,
         }
     }
 
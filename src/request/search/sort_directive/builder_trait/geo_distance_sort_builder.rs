@@ -8,6 +8,7 @@ pub struct GeoDistanceSortBuilder {
     pub(super) order: Option<SortDirection>,
     pub(super) ignore_unmapped: Option<bool>,
     pub(super) calc_formula: Option<CalculationFormula>,
+    pub(super) unit: Option<DistanceUnit>,
 }
 
 impl GeoDistanceSortBuilder {
@@ -45,6 +46,13 @@ impl GeoDistanceSortBuilder {
         self.ignore_unmapped = Some(true);
         self
     }
+
+    /// Report the sort distance of each document in
+    /// this unit instead of meters
+    pub fn measured_in(mut self, unit: DistanceUnit) -> Self {
+        self.unit = Some(unit);
+        self
+    }
 }
 
 impl From<GeoDistanceSortBuilder> for SortDirective {
@@ -55,6 +63,7 @@ impl From<GeoDistanceSortBuilder> for SortDirective {
             order: value.order,
             ignore_unmapped: value.ignore_unmapped,
             calc_formula: value.calc_formula,
+            unit: value.unit,
         })
     }
 }
@@ -1,16 +1,15 @@
 use super::*;
-use std::borrow::Cow;
 
 /// Aids in the construction of a script score
 #[derive(Debug, Clone)]
 pub struct ScriptScoreBuilder {
-    script: Cow<'static, str>,
+    script: ScriptSource,
     params: Option<ScriptParams>,
 }
 
 impl ScriptScoreBuilder {
     /// Start the construction of a script score
-    pub fn new<S: Into<Cow<'static, str>>>(script: S) -> Self {
+    pub fn new<S: Into<ScriptSource>>(script: S) -> Self {
         Self {
             script: script.into(),
             params: None,
@@ -1,10 +1,9 @@
 use super::*;
-use std::borrow::Cow;
 
 /// Aids in the construction of a script score
 #[derive(Debug, Clone, Default)]
 pub struct ScriptSortBuilder {
-    source: Cow<'static, str>,
+    source: ScriptSource,
     params: Option<ScriptParams>,
     return_type: Option<ScriptReturnType>,
     direction: Option<SortDirection>,
@@ -12,7 +11,7 @@ pub struct ScriptSortBuilder {
 
 impl ScriptSortBuilder {
     /// Start the construction of a script score
-    pub fn new<S: Into<Cow<'static, str>>>(source: S) -> Self {
+    pub fn new<S: Into<ScriptSource>>(source: S) -> Self {
         Self {
             source: source.into(),
             params: None,
@@ -10,6 +10,7 @@ pub struct SortGeo {
     pub(super) order: Option<SortDirection>,
     pub(super) ignore_unmapped: Option<bool>,
     pub(super) calc_formula: Option<CalculationFormula>,
+    pub(super) unit: Option<DistanceUnit>,
 }
 
 impl SortGeo {
@@ -25,6 +26,7 @@ impl SortGeo {
             order: None,
             ignore_unmapped: None,
             calc_formula: None,
+            unit: None,
         }
     }
 }
@@ -44,6 +46,7 @@ pub struct SortGeoBuilder {
     order: Option<SortDirection>,
     ignore_unmapped: Option<bool>,
     calc_formula: Option<CalculationFormula>,
+    unit: Option<DistanceUnit>,
 }
 
 impl SortGeoBuilder {
@@ -55,6 +58,7 @@ impl SortGeoBuilder {
             order: self.order,
             ignore_unmapped: self.ignore_unmapped,
             calc_formula: self.calc_formula,
+            unit: self.unit,
         }
     }
 
@@ -102,6 +106,15 @@ impl SortGeoBuilder {
             ..self
         }
     }
+
+    /// Report the sort distance of each document in
+    /// this unit instead of meters
+    pub fn measured_in(self, unit: DistanceUnit) -> Self {
+        Self {
+            unit: Some(unit),
+            ..self
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -133,6 +146,7 @@ impl Serialize for SortGeo {
             order: &'a Option<SortDirection>,
             ignore_unmapped: &'a Option<bool>,
             calc_formula: &'a Option<CalculationFormula>,
+            unit: &'a Option<DistanceUnit>,
         }
 
         impl<'a> Params<'a> {
@@ -141,6 +155,7 @@ impl Serialize for SortGeo {
                     self.order.is_some(),
                     self.ignore_unmapped.is_some(),
                     self.calc_formula.is_some(),
+                    self.unit.is_some(),
                 ]
                 .iter()
                 .filter(|something| **something)
@@ -155,6 +170,7 @@ impl Serialize for SortGeo {
                     order: &sort_geo.order,
                     ignore_unmapped: &sort_geo.ignore_unmapped,
                     calc_formula: &sort_geo.calc_formula,
+                    unit: &sort_geo.unit,
                 }
             }
         }
@@ -176,6 +192,10 @@ impl Serialize for SortGeo {
                     map.serialize_entry("distance_type", formula)?;
                 }
 
+                if let Some(unit) = self.unit {
+                    map.serialize_entry("unit", unit)?;
+                }
+
                 if let Some(ignore) = self.ignore_unmapped {
                     map.serialize_entry("ignore_unmapped", ignore)?;
                 }
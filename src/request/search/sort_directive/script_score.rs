@@ -1,22 +1,22 @@
 use super::*;
 use serde::Serialize;
-use std::borrow::Cow;
 
 /// Data used to build a `script_score` for an Elasticsearch
 /// request.  Currently this is not a full representation of
 /// what is used, but instead the most common.
 #[derive(Debug, Clone, Default, Serialize)]
 pub struct ScriptScoreData {
-    #[serde(rename = "source")]
-    pub(super) script: Cow<'static, str>,
+    #[serde(flatten)]
+    pub(super) script: ScriptSource,
 
     #[serde(skip_serializing_if = "ScriptParams::is_empty")]
     pub(super) params: ScriptParams,
 }
 
 impl ScriptScoreData {
-    /// Creates a new script sort w/o any params
-    pub fn new<S: Into<Cow<'static, str>>>(script: S) -> Self {
+    /// Creates a new script sort w/o any params.  The script
+    /// can be the source itself or a [ScriptSource::Stored] id.
+    pub fn new<S: Into<ScriptSource>>(script: S) -> Self {
         Self {
             script: script.into(),
             params: ScriptParams::default(),
@@ -26,7 +26,7 @@ impl ScriptScoreData {
     /// Creates a new script sort with params
     pub fn new_with_params<S>(script: S, params: ScriptParams) -> Self
     where
-        S: Into<Cow<'static, str>>,
+        S: Into<ScriptSource>,
     {
         Self {
             params,
@@ -1,6 +1,5 @@
 use super::*;
 use serde::ser::Serialize;
-use std::borrow::Cow;
 
 /// Describes what primitive the script returns
 #[derive(Debug, Clone, Copy, Default)]
@@ -19,7 +18,7 @@ pub struct ScriptSort {
     pub(super) return_type: ScriptReturnType,
     pub(super) params: ScriptParams,
     pub(super) direction: Option<SortDirection>,
-    pub(super) source: Cow<'static, str>,
+    pub(super) source: ScriptSource,
 }
 
 // Serialize Impls
@@ -67,7 +66,8 @@ impl Serialize for ScriptSort {
 
         #[derive(Serialize)]
         struct ScriptDetails<'a> {
-            source: &'a Cow<'static, str>,
+            #[serde(flatten)]
+            source: &'a ScriptSource,
             #[serde(skip_serializing_if = "ScriptParams::is_empty")]
             params: &'a ScriptParams,
         }
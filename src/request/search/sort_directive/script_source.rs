@@ -0,0 +1,35 @@
+use serde::Serialize;
+use std::borrow::Cow;
+
+/// Where the body of a script comes from; either written
+/// out with the request or stored on the server ahead of
+/// time and referenced by id.
+#[derive(Debug, Clone, Serialize)]
+pub enum ScriptSource {
+    /// source of the script sent with every request
+    #[serde(rename = "source")]
+    Inline(Cow<'static, str>),
+
+    /// id of a script stored with [crate::client::Client::put_script]
+    #[serde(rename = "id")]
+    Stored(Cow<'static, str>),
+}
+
+impl Default for ScriptSource {
+    fn default() -> Self {
+        Self::Inline(Cow::Borrowed(""))
+    }
+}
+
+impl ScriptSource {
+    /// Reference a script stored on the server by id
+    pub fn stored<S: Into<Cow<'static, str>>>(id: S) -> Self {
+        Self::Stored(id.into())
+    }
+}
+
+impl<S: Into<Cow<'static, str>>> From<S> for ScriptSource {
+    fn from(value: S) -> Self {
+        Self::Inline(value.into())
+    }
+}
@@ -0,0 +1,30 @@
+use serde::Serialize;
+use std::borrow::Cow;
+
+use super::search::ScriptParams;
+
+/// Search Template
+///
+/// Runs a mustache [super::StoredScript] as a search, filling
+/// it in with the given params.
+///
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchTemplate {
+    id: Cow<'static, str>,
+    #[serde(skip_serializing_if = "ScriptParams::is_empty")]
+    params: ScriptParams,
+}
+
+impl SearchTemplate {
+    /// Creates a search from the template stored under the id
+    pub fn new<S, P>(id: S, params: P) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+        P: Into<ScriptParams>,
+    {
+        Self {
+            id: id.into(),
+            params: params.into(),
+        }
+    }
+}
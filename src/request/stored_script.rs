@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// Stored Script
+///
+/// A script kept on the server under an id with
+/// [crate::client::Client::put_script] so that requests can
+/// reference it with [crate::request::search::ScriptSource::Stored]
+/// instead of sending and compiling the source every time.  A
+/// mustache script serves as a search template for
+/// [crate::client::Client::search_template].
+///
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StoredScript {
+    lang: Cow<'static, str>,
+    source: Cow<'static, str>,
+}
+
+impl StoredScript {
+    /// A painless script, such as one used to score or sort
+    pub fn painless<S: Into<Cow<'static, str>>>(source: S) -> Self {
+        Self {
+            lang: Cow::Borrowed("painless"),
+            source: source.into(),
+        }
+    }
+
+    /// A mustache search template
+    pub fn mustache<S: Into<Cow<'static, str>>>(source: S) -> Self {
+        Self {
+            lang: Cow::Borrowed("mustache"),
+            source: source.into(),
+        }
+    }
+
+    /// language the script is written in
+    pub fn lang(&self) -> &str {
+        &self.lang
+    }
+
+    /// body of the script
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+}
@@ -0,0 +1,101 @@
+use crate::response::VersionedDocument;
+use std::borrow::Cow;
+
+/// Write Options
+///
+/// Conditions a write must meet to be applied, which is how
+/// optimistic concurrency is done.  A write that doesn't meet
+/// its condition fails with
+/// [crate::client::ClientError::VersionConflict].
+///
+/// ```rust
+/// use elastic_lens::request::WriteOptions;
+///
+/// // only write if nobody else has since the document was read
+/// let options = WriteOptions::new().if_seq_no(42, 1);
+///
+/// // keep a child document on the same shard as it's parent
+/// let options = WriteOptions::new().routing("order-42");
+/// ```
+///
+#[derive(Debug, Clone, Default)]
+pub struct WriteOptions {
+    pub(crate) condition: Option<WriteCondition>,
+    pub(crate) routing: Option<Cow<'static, str>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum WriteCondition {
+    SeqNo { seq_no: u64, primary_term: u64 },
+    External(u64),
+    ExternalGte(u64),
+}
+
+impl WriteOptions {
+    /// Options without any conditions, the write always applies
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// If Sequence Number
+    ///
+    /// Only apply the write if the document was last changed at
+    /// this sequence number and primary term, which are handed
+    /// back when reading with [crate::client::Client::get_versioned_by_id].
+    ///
+    pub fn if_seq_no(self, seq_no: u64, primary_term: u64) -> Self {
+        Self {
+            condition: Some(WriteCondition::SeqNo {
+                seq_no,
+                primary_term,
+            }),
+            ..self
+        }
+    }
+
+    /// External Version
+    ///
+    /// Use a version kept outside of Elasticsearch, such as from
+    /// a primary database.  The write only applies if the version
+    /// is greater than the version of the stored document.
+    ///
+    pub fn external_version(self, version: u64) -> Self {
+        Self {
+            condition: Some(WriteCondition::External(version)),
+            ..self
+        }
+    }
+
+    /// External Version or Equal
+    ///
+    /// The same as [WriteOptions::external_version] except the
+    /// write also applies if the versions are equal.
+    ///
+    pub fn external_gte_version(self, version: u64) -> Self {
+        Self {
+            condition: Some(WriteCondition::ExternalGte(version)),
+            ..self
+        }
+    }
+
+    /// Routing
+    ///
+    /// Sends the write to the shard for this value instead of
+    /// the one for the document id.  Children of a `join` field
+    /// must be routed by the id of their parent so they land on
+    /// the same shard, and deleting them needs the same routing.
+    /// Read them back with [crate::client::Client::get_by_id_routed].
+    ///
+    pub fn routing<S: Into<Cow<'static, str>>>(self, routing: S) -> Self {
+        Self {
+            routing: Some(routing.into()),
+            ..self
+        }
+    }
+}
+
+impl<T> From<&VersionedDocument<T>> for WriteOptions {
+    fn from(value: &VersionedDocument<T>) -> Self {
+        Self::new().if_seq_no(value.seq_no, value.primary_term)
+    }
+}
@@ -1,8 +1,31 @@
 //! All of the logic for parsing and working with a response is in this module.
 
+mod async_search;
+mod cluster_health;
+mod explanation;
+mod field_caps;
+mod index_stats;
+pub(crate) mod index_template;
 mod multi_results;
+pub(crate) mod point_in_time;
+mod profile;
+mod query_validation;
 mod search_results;
+mod server_info;
 pub(crate) mod single_document;
+pub(crate) mod stored_script;
+mod task_status;
+mod versioned_document;
 
+pub use async_search::*;
+pub use cluster_health::*;
+pub use explanation::*;
+pub use field_caps::*;
+pub use index_stats::*;
 pub use multi_results::*;
+pub use profile::*;
+pub use query_validation::*;
 pub use search_results::*;
+pub use server_info::*;
+pub use task_status::*;
+pub use versioned_document::*;
@@ -0,0 +1,61 @@
+use super::SearchResults;
+use serde::Deserialize;
+use std::borrow::Cow;
+
+/// Async Search Handle
+///
+/// Produced by [crate::client::Client::async_search] and kept
+/// up to date by [crate::client::Client::poll_async_search].
+/// Once the search is no longer running the results are final.
+/// Results still held on the server can be removed with
+/// [crate::client::Client::delete_async_search].
+///
+#[derive(Debug, Deserialize)]
+#[serde(bound(deserialize = "T: Deserialize<'de>"))]
+pub struct AsyncSearchHandle<T> {
+    #[serde(default)]
+    id: Option<String>,
+    is_running: bool,
+    is_partial: bool,
+    #[serde(default)]
+    response: Option<SearchResults<T>>,
+    #[serde(skip)]
+    pub(crate) keep_alive: Cow<'static, str>,
+}
+
+impl<T> AsyncSearchHandle<T> {
+    /// Id the server issued for the search.  This is missing when
+    /// the search finished before the request returned, as the
+    /// server doesn't hold onto results it has already handed back.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// is the search still running on the server
+    pub fn is_running(&self) -> bool {
+        self.is_running
+    }
+
+    /// are the results missing some shards, which is
+    /// the case while running or if any shards failed
+    pub fn is_partial(&self) -> bool {
+        self.is_partial
+    }
+
+    /// results gathered so far, if any
+    pub fn results(&self) -> Option<&SearchResults<T>> {
+        self.response.as_ref()
+    }
+
+    /// takes the results gathered so far out of the handle
+    pub fn take_results(&mut self) -> Option<SearchResults<T>> {
+        self.response.take()
+    }
+
+    pub(crate) fn update(&mut self, polled: Self) {
+        self.id = polled.id.or(self.id.take());
+        self.is_running = polled.is_running;
+        self.is_partial = polled.is_partial;
+        self.response = polled.response;
+    }
+}
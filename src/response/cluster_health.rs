@@ -0,0 +1,58 @@
+use serde::Deserialize;
+
+/// Overall health of the cluster as reported by the server
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClusterHealth {
+    /// name of the cluster
+    pub cluster_name: String,
+
+    /// traffic light status of the cluster
+    pub status: HealthStatus,
+
+    /// true if the wait for a status ran out of time
+    pub timed_out: bool,
+
+    /// how many nodes are in the cluster
+    pub number_of_nodes: u32,
+
+    /// how many of the nodes hold data
+    pub number_of_data_nodes: u32,
+
+    /// primary shards which are active
+    pub active_primary_shards: u32,
+
+    /// primary and replica shards which are active
+    pub active_shards: u32,
+
+    /// shards which are moving between nodes
+    pub relocating_shards: u32,
+
+    /// shards which are being set up
+    pub initializing_shards: u32,
+
+    /// shards which have not been assigned to a node
+    pub unassigned_shards: u32,
+
+    /// cluster level changes which have not been executed yet
+    pub number_of_pending_tasks: u32,
+
+    /// ratio of active shards in the cluster as a percentage
+    pub active_shards_percent_as_number: f64,
+}
+
+/// Health of a cluster, from best to worst:
+///
+/// - `Green`: all shards are assigned
+/// - `Yellow`: all primary shards are assigned, but some replicas are not
+/// - `Red`: at least one primary shard is not assigned
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    /// all shards are assigned
+    Green,
+    /// all primary shards are assigned, but some replicas are not
+    Yellow,
+    /// at least one primary shard is not assigned
+    Red,
+}
@@ -0,0 +1,36 @@
+use serde::Deserialize;
+
+/// How a score was computed, broken down into
+/// the pieces which went into it
+#[derive(Debug, Clone, Deserialize)]
+pub struct Explanation {
+    /// the score this part contributed
+    pub value: f64,
+
+    /// what the server did to come up with the value
+    pub description: String,
+
+    /// the explanations which make up this value
+    #[serde(default)]
+    pub details: Vec<Explanation>,
+}
+
+/// Response from explaining how a single document
+/// scores against a search with [crate::client::Client::explain]
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExplainResult {
+    /// id of the document
+    #[serde(rename = "_id")]
+    pub id: String,
+
+    /// index the document lives in
+    #[serde(rename = "_index")]
+    pub index: String,
+
+    /// did the document match the search
+    pub matched: bool,
+
+    /// how the score was computed, if it matched
+    #[serde(default)]
+    pub explanation: Option<Explanation>,
+}
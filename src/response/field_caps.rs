@@ -0,0 +1,151 @@
+use crate::request::search::{referenced_fields, FieldUsage, SearchTrait};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// Capabilities of fields across the indices they are
+/// looked up for with [crate::client::Client::field_caps]
+#[derive(Debug, Clone, Deserialize)]
+pub struct FieldCaps {
+    /// every index the fields were looked up in
+    #[serde(default)]
+    pub indices: Vec<String>,
+
+    /// for each field, the capabilities of each type it's mapped as
+    pub fields: BTreeMap<String, BTreeMap<String, FieldCapability>>,
+}
+
+/// What a field can do when mapped as a type.  When the field
+/// is mapped as this type in every index the index lists are
+/// left out.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FieldCapability {
+    /// type of the mapping, which is `"unmapped"` for the
+    /// indices where the field isn't mapped at all
+    #[serde(rename = "type")]
+    pub field_type: String,
+
+    /// can the field be queried in every index
+    pub searchable: bool,
+
+    /// can the field be aggregated, sorted, or collapsed in every index
+    pub aggregatable: bool,
+
+    /// indices with the field mapped as this type
+    #[serde(default)]
+    pub indices: Option<Vec<String>>,
+
+    /// indices where the field can't be queried
+    #[serde(default)]
+    pub non_searchable_indices: Option<Vec<String>>,
+
+    /// indices where the field can't be aggregated
+    #[serde(default)]
+    pub non_aggregatable_indices: Option<Vec<String>>,
+}
+
+/// Something wrong with how a search uses a field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldProblem {
+    /// name of the field
+    pub field: String,
+
+    /// how the search uses the field
+    pub usage: FieldUsage,
+
+    /// what is wrong
+    pub issue: FieldIssue,
+}
+
+/// The ways a field can be unfit for how a search uses it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldIssue {
+    /// no index has the field mapped
+    Unmapped,
+
+    /// some indices, which are listed, don't have the field mapped
+    UnmappedIn(Vec<String>),
+
+    /// the field is mapped as more than one type, which are listed
+    TypeConflict(Vec<String>),
+
+    /// the field can't be aggregated, sorted, or collapsed
+    NotAggregatable,
+}
+
+const UNMAPPED: &str = "unmapped";
+
+impl FieldCaps {
+    /// capabilities of each type a field is mapped as, not
+    /// including the indices where it's unmapped
+    pub fn types(&self, field: &str) -> impl Iterator<Item = &FieldCapability> {
+        self.fields
+            .get(field)
+            .into_iter()
+            .flat_map(|types| types.values())
+            .filter(|cap| cap.field_type != UNMAPPED)
+    }
+
+    /// is the field mapped in at least one index
+    pub fn is_mapped(&self, field: &str) -> bool {
+        self.types(field).next().is_some()
+    }
+
+    /// Checks every field referenced by the search, finding
+    /// any that are unmapped, mapped in conflicting ways, or
+    /// not aggregatable where they are aggregated, sorted, or
+    /// collapsed.  Each field and usage is reported at most
+    /// once per issue.
+    pub fn problems<S: SearchTrait>(&self, search: &S) -> Vec<FieldProblem> {
+        let mut problems: Vec<FieldProblem> = vec![];
+
+        for reference in referenced_fields(search) {
+            let name = reference.field.name();
+            let problem = |issue| FieldProblem {
+                field: name.to_owned(),
+                usage: reference.usage,
+                issue,
+            };
+
+            for issue in self.issues(name, reference.usage) {
+                let problem = problem(issue);
+
+                if !problems.contains(&problem) {
+                    problems.push(problem);
+                }
+            }
+        }
+
+        problems
+    }
+
+    fn issues(&self, field: &str, usage: FieldUsage) -> Vec<FieldIssue> {
+        if !self.is_mapped(field) {
+            return vec![FieldIssue::Unmapped];
+        }
+
+        let mut issues = vec![];
+        let types = &self.fields[field];
+
+        if let Some(unmapped) = types.get(UNMAPPED) {
+            let indices = unmapped.indices.clone();
+            issues.push(FieldIssue::UnmappedIn(
+                indices.unwrap_or_else(|| self.indices.clone()),
+            ));
+        }
+
+        let mapped: Vec<String> = self
+            .types(field)
+            .map(|cap| cap.field_type.clone())
+            .collect();
+
+        if mapped.len() > 1 {
+            issues.push(FieldIssue::TypeConflict(mapped));
+        }
+
+        if usage.needs_aggregatable() && !self.types(field).all(|cap| cap.aggregatable) {
+            issues.push(FieldIssue::NotAggregatable);
+        }
+
+        issues
+    }
+}
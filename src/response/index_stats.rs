@@ -0,0 +1,100 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// Statistics for the index ( or indexes ) the client works with
+#[derive(Debug, Clone, Deserialize)]
+pub struct IndexStats {
+    /// combined statistics across every index
+    #[serde(rename = "_all")]
+    pub all: StatsTotals,
+
+    /// statistics for each index, keyed by the index name
+    #[serde(default)]
+    pub indices: BTreeMap<String, IndexStatsDetail>,
+}
+
+/// Statistics for a single index
+#[derive(Debug, Clone, Deserialize)]
+pub struct IndexStatsDetail {
+    /// unique id of the index
+    #[serde(default)]
+    pub uuid: Option<String>,
+
+    /// statistics for primary shards
+    pub primaries: StatsSummary,
+
+    /// statistics for primary and replica shards
+    pub total: StatsSummary,
+
+    /// statistics for every copy of each shard, keyed by shard number
+    #[serde(default)]
+    pub shards: BTreeMap<String, Vec<ShardDetail>>,
+}
+
+/// Statistics split by primary shards and every shard
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct StatsTotals {
+    /// statistics for primary shards
+    pub primaries: StatsSummary,
+
+    /// statistics for primary and replica shards
+    pub total: StatsSummary,
+}
+
+/// Document and storage numbers for a group of shards
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub struct StatsSummary {
+    /// document counts
+    #[serde(default)]
+    pub docs: DocStats,
+
+    /// storage sizes
+    #[serde(default)]
+    pub store: StoreStats,
+}
+
+/// Document counts
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub struct DocStats {
+    /// documents which are searchable
+    pub count: u64,
+
+    /// documents which are deleted but not yet merged away
+    pub deleted: u64,
+}
+
+/// Storage sizes
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub struct StoreStats {
+    /// bytes used on disk
+    pub size_in_bytes: u64,
+}
+
+/// Statistics for a single copy of a shard
+#[derive(Debug, Clone, Deserialize)]
+pub struct ShardDetail {
+    /// where the shard copy lives
+    pub routing: ShardRouting,
+
+    /// document counts
+    #[serde(default)]
+    pub docs: DocStats,
+
+    /// storage sizes
+    #[serde(default)]
+    pub store: StoreStats,
+}
+
+/// Where a shard copy lives in the cluster
+#[derive(Debug, Clone, Deserialize)]
+pub struct ShardRouting {
+    /// state such as `STARTED` or `RELOCATING`
+    pub state: String,
+
+    /// true if this copy is the primary
+    pub primary: bool,
+
+    /// id of the node holding the copy
+    #[serde(default)]
+    pub node: Option<String>,
+}
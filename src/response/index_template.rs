@@ -0,0 +1,28 @@
+use crate::request::{ComponentTemplate, IndexTemplate};
+use serde::Deserialize;
+
+/// Response from fetching index templates by name
+#[derive(Debug, Deserialize)]
+pub(crate) struct FetchedIndexTemplates {
+    #[serde(default)]
+    pub(crate) index_templates: Vec<NamedIndexTemplate>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct NamedIndexTemplate {
+    pub(crate) name: String,
+    pub(crate) index_template: IndexTemplate,
+}
+
+/// Response from fetching component templates by name
+#[derive(Debug, Deserialize)]
+pub(crate) struct FetchedComponentTemplates {
+    #[serde(default)]
+    pub(crate) component_templates: Vec<NamedComponentTemplate>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct NamedComponentTemplate {
+    pub(crate) name: String,
+    pub(crate) component_template: ComponentTemplate,
+}
@@ -0,0 +1,9 @@
+use serde::Deserialize;
+
+/// Response from opening a point in time.  Elasticsearch
+/// calls the field `id` while OpenSearch calls it `pit_id`.
+#[derive(Debug, Deserialize)]
+pub(crate) struct OpenedPointInTime {
+    #[serde(alias = "pit_id")]
+    pub(crate) id: String,
+}
@@ -0,0 +1,94 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// Where time was spent executing a search, which is
+/// requested with [crate::request::search::Search::set_profile]
+#[derive(Debug, Clone, Deserialize)]
+pub struct SearchProfile {
+    /// timings from each shard which took part in the search
+    pub shards: Vec<ShardProfile>,
+}
+
+/// Timings from a single shard
+#[derive(Debug, Clone, Deserialize)]
+pub struct ShardProfile {
+    /// identifies the node, index, and shard number
+    pub id: String,
+
+    /// timings of the query phase
+    #[serde(default)]
+    pub searches: Vec<QueryPhaseProfile>,
+
+    /// timings of each aggregation
+    #[serde(default)]
+    pub aggregations: Vec<OperationProfile>,
+}
+
+/// Timings of the query phase on a shard
+#[derive(Debug, Clone, Deserialize)]
+pub struct QueryPhaseProfile {
+    /// the tree of queries that were executed
+    pub query: Vec<OperationProfile>,
+
+    /// nanoseconds spent rewriting the query
+    pub rewrite_time: u64,
+
+    /// the collectors that gathered up the hits
+    #[serde(default)]
+    pub collector: Vec<CollectorProfile>,
+}
+
+/// Timing of a query or aggregation along with
+/// the operations it is made up of
+#[derive(Debug, Clone, Deserialize)]
+pub struct OperationProfile {
+    /// Lucene class name of the query or aggregation
+    #[serde(rename = "type")]
+    pub kind: String,
+
+    /// the query or aggregation as the server understood it
+    pub description: String,
+
+    /// total nanoseconds spent, including children
+    pub time_in_nanos: u64,
+
+    /// nanoseconds or counts for each step of the execution
+    #[serde(default)]
+    pub breakdown: BTreeMap<String, u64>,
+
+    /// operations that make up this one
+    #[serde(default)]
+    pub children: Vec<OperationProfile>,
+}
+
+/// Timing of a collector from the query phase
+#[derive(Debug, Clone, Deserialize)]
+pub struct CollectorProfile {
+    /// Lucene class name of the collector
+    pub name: String,
+
+    /// why the collector was used
+    pub reason: String,
+
+    /// total nanoseconds spent, including children
+    pub time_in_nanos: u64,
+
+    /// collectors wrapped by this one
+    #[serde(default)]
+    pub children: Vec<CollectorProfile>,
+}
+
+impl OperationProfile {
+    /// total time spent, including children
+    pub fn time(&self) -> Duration {
+        Duration::from_nanos(self.time_in_nanos)
+    }
+}
+
+impl CollectorProfile {
+    /// total time spent, including children
+    pub fn time(&self) -> Duration {
+        Duration::from_nanos(self.time_in_nanos)
+    }
+}
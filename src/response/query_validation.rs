@@ -0,0 +1,49 @@
+use serde::Deserialize;
+
+/// Response from validating the query of a search
+/// with [crate::client::Client::validate]
+#[derive(Debug, Clone, Deserialize)]
+pub struct QueryValidation {
+    /// is the query valid for every index it targets
+    pub valid: bool,
+
+    /// why the query is invalid, when it can't be tied to an index
+    #[serde(default)]
+    pub error: Option<String>,
+
+    /// details from each index the query targets
+    #[serde(default)]
+    pub explanations: Vec<QueryExplanation>,
+}
+
+/// How a query was understood by a single index
+#[derive(Debug, Clone, Deserialize)]
+pub struct QueryExplanation {
+    /// name of the index
+    pub index: String,
+
+    /// is the query valid for this index
+    pub valid: bool,
+
+    /// the query rewritten into Lucene syntax, when valid
+    #[serde(default)]
+    pub explanation: Option<String>,
+
+    /// why the query is invalid for this index
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+impl QueryValidation {
+    /// every reason the query was found invalid
+    pub fn errors(&self) -> impl Iterator<Item = &str> {
+        self.error
+            .iter()
+            .chain(
+                self.explanations
+                    .iter()
+                    .filter_map(|exp| exp.error.as_ref()),
+            )
+            .map(String::as_str)
+    }
+}
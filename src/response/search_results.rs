@@ -1,5 +1,7 @@
+use serde::de::DeserializeOwned;
 use serde::de::{self, Deserializer, MapAccess, Visitor};
 use serde::Deserialize;
+use std::collections::BTreeMap;
 use std::fmt::{self, Debug};
 use std::marker::PhantomData;
 use std::time::Duration;
@@ -8,6 +10,8 @@ mod agg_result;
 
 pub use agg_result::*;
 
+use super::{Explanation, SearchProfile};
+
 /// The data that comes back from an Elasticsearch search
 pub struct SearchResults<T> {
     search_time: Duration,
@@ -18,6 +22,8 @@ pub struct SearchResults<T> {
     hits: Vec<DocumentHit<T>>,
     aggs: AggResultCollection,
     scroll_id: Option<String>,
+    pit_id: Option<String>,
+    profile: Option<SearchProfile>,
 }
 
 impl<T: Debug> Debug for SearchResults<T> {
@@ -30,6 +36,7 @@ impl<T: Debug> Debug for SearchResults<T> {
             .field("max_score", &self.max_score)
             .field("hits", &self.hits)
             .field("aggs", &self.aggs)
+            .field("profile", &self.profile)
             .finish()
     }
 }
@@ -45,6 +52,8 @@ impl<T: Clone> Clone for SearchResults<T> {
             hits: self.hits.clone(),
             aggs: self.aggs.clone(),
             scroll_id: self.scroll_id.clone(),
+            pit_id: self.pit_id.clone(),
+            profile: self.profile.clone(),
         }
     }
 }
@@ -129,10 +138,21 @@ impl<T> SearchResults<T> {
         &mut self.aggs
     }
 
+    /// Where time was spent on each shard, if the search
+    /// was made with [crate::request::search::Search::set_profile]
+    pub fn profile(&self) -> Option<&SearchProfile> {
+        self.profile.as_ref()
+    }
+
     #[doc(hidden)]
     pub(crate) fn take_scroll_id(&mut self) -> Option<String> {
         self.scroll_id.take()
     }
+
+    #[doc(hidden)]
+    pub(crate) fn take_pit_id(&mut self) -> Option<String> {
+        self.pit_id.take()
+    }
 }
 
 /// Idea of how many results matched a search
@@ -184,6 +204,125 @@ pub struct DocumentHit<T> {
     /// the document data
     #[serde(rename = "_source")]
     pub doc: T,
+
+    /// how the score was computed, if the search was
+    /// made with [crate::request::search::Search::set_explain]
+    #[serde(rename = "_explanation", default)]
+    pub explanation: Option<Explanation>,
+
+    /// which of the percolated documents matched this stored
+    /// search, by their position, when searching with
+    /// [crate::request::search::PercolateFilter]
+    #[serde(
+        rename = "fields",
+        default,
+        deserialize_with = "percolator_document_slots"
+    )]
+    pub percolator_document_slots: Vec<usize>,
+
+    /// the inner hits by name, when searching with
+    /// [crate::request::search::InnerHits] on nested,
+    /// has_child or has_parent criteria
+    #[serde(default)]
+    pub inner_hits: BTreeMap<String, InnerHitResults>,
+}
+
+/// The hits of one of the inner hits of a [DocumentHit]
+#[derive(Debug, Clone)]
+pub struct InnerHitResults {
+    /// how many inner documents matched, if it was tracked
+    pub count: Option<ResultCount>,
+
+    /// highest score of the inner documents
+    pub max_score: Option<f64>,
+
+    /// the inner documents returned
+    pub hits: Vec<InnerHit>,
+}
+
+/// A document from the inner hits of a [DocumentHit], which
+/// can be any type so is kept as JSON until read with
+/// [InnerHit::doc_as]
+#[derive(Debug, Clone, Deserialize)]
+pub struct InnerHit {
+    /// id of the inner document, or of it's parent when nested
+    #[serde(rename = "_id", default)]
+    pub id: Option<String>,
+
+    /// index the inner document came from
+    #[serde(rename = "_index", default)]
+    pub index: Option<String>,
+
+    /// where a nested document sits in it's parent
+    #[serde(rename = "_nested", default)]
+    pub nested: Option<NestedIdentity>,
+
+    /// relievence score of the inner document
+    #[serde(rename = "_score", default)]
+    pub score: Option<f64>,
+
+    /// the inner document data, null if the source was left out
+    #[serde(rename = "_source", default)]
+    pub doc: serde_json::Value,
+}
+
+/// The field and position of a nested inner hit
+#[derive(Debug, Clone, Deserialize)]
+pub struct NestedIdentity {
+    /// the nested field the document is from
+    pub field: String,
+
+    /// position of the document in the field
+    pub offset: usize,
+}
+
+impl InnerHit {
+    /// reads the inner document as the given type
+    pub fn doc_as<U: DeserializeOwned>(&self) -> Result<U, serde_json::Error> {
+        U::deserialize(&self.doc)
+    }
+}
+
+impl<'de> Deserialize<'de> for InnerHitResults {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Outer {
+            hits: Inner,
+        }
+
+        #[derive(Deserialize)]
+        struct Inner {
+            #[serde(default)]
+            total: Option<ResultCount>,
+            #[serde(default)]
+            max_score: Option<f64>,
+            hits: Vec<InnerHit>,
+        }
+
+        let Outer { hits } = Outer::deserialize(deserializer)?;
+
+        Ok(Self {
+            count: hits.total,
+            max_score: hits.max_score,
+            hits: hits.hits,
+        })
+    }
+}
+
+fn percolator_document_slots<'de, D>(deserializer: D) -> Result<Vec<usize>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct HitFields {
+        #[serde(rename = "_percolator_document_slot", default)]
+        slots: Vec<usize>,
+    }
+
+    Ok(HitFields::deserialize(deserializer)?.slots)
 }
 
 impl<T: Debug> Debug for DocumentHit<T> {
@@ -194,6 +333,9 @@ impl<T: Debug> Debug for DocumentHit<T> {
             .field("doc_type", &self.doc_type)
             .field("score", &self.score)
             .field("doc", &self.doc)
+            .field("explanation", &self.explanation)
+            .field("percolator_document_slots", &self.percolator_document_slots)
+            .field("inner_hits", &self.inner_hits)
             .finish()
     }
 }
@@ -206,6 +348,9 @@ impl<T: Clone> Clone for DocumentHit<T> {
             doc_type: self.doc_type.clone(),
             score: self.score,
             doc: self.doc.clone(),
+            explanation: self.explanation.clone(),
+            percolator_document_slots: self.percolator_document_slots.clone(),
+            inner_hits: self.inner_hits.clone(),
         }
     }
 }
@@ -244,6 +389,15 @@ where
                         formatter.write_str("ResultCount data")
                     }
 
+                    // Older servers, and OpenSearch with `rest_total_hits_as_int`,
+                    // report the total as a plain number which is always exact
+                    fn visit_u64<E>(self, amount: u64) -> Result<Self::Value, E>
+                    where
+                        E: de::Error,
+                    {
+                        Ok(ResultCount::Exactly(amount as usize))
+                    }
+
                     fn visit_map<M>(self, mut map: M) -> Result<Self::Value, M::Error>
                     where
                         M: MapAccess<'de>,
@@ -281,7 +435,7 @@ where
                     }
                 }
 
-                deserializer.deserialize_map(ResultCountVistor)
+                deserializer.deserialize_any(ResultCountVistor)
             }
         }
 
@@ -291,23 +445,15 @@ where
 
         #[derive(Deserialize)]
         struct Hits<T> {
-            total: ResultCount,
+            // missing when `track_total_hits` is turned off
+            #[serde(default)]
+            total: Option<ResultCount>,
             max_score: Option<f64>,
             hits: Vec<DocumentHit<T>>,
         }
 
         struct ResultsVistor<T>(PhantomData<T>);
 
-        const FIELDS: &[&str; 7] = &[
-            "hits",
-            "aggregations",
-            "took",
-            "timed_out",
-            "_shards",
-            "status",
-            "_scroll_id",
-        ];
-
         impl<'de, T: Deserialize<'de>> Visitor<'de> for ResultsVistor<T> {
             type Value = SearchResults<T>;
 
@@ -325,6 +471,8 @@ where
                 let mut took: Option<Duration> = None;
                 let mut aggs: Option<AggResultCollection> = None;
                 let mut scroll_id: Option<String> = None;
+                let mut pit_id: Option<String> = None;
+                let mut profile: Option<SearchProfile> = None;
 
                 while let Some(key) = map.next_key()? {
                     match key {
@@ -346,6 +494,12 @@ where
                         "_scroll_id" => {
                             scroll_id = Some(map.next_value()?);
                         }
+                        "pit_id" => {
+                            pit_id = Some(map.next_value()?);
+                        }
+                        "profile" => {
+                            profile = Some(map.next_value()?);
+                        }
                         // TODO: This wires up msearch to get it working as each
                         //       result has a status in this case... but in the
                         //       case of failure we'll need to figure out what
@@ -360,7 +514,11 @@ where
                                 )));
                             }
                         }
-                        unknown => Err(de::Error::unknown_field(unknown, FIELDS))?,
+                        // newer servers keep adding details to the response,
+                        // such as `num_reduce_phases` and `_clusters`
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
                     }
                 }
 
@@ -369,16 +527,19 @@ where
                 let timed_out = timed_out.ok_or_else(|| de::Error::missing_field("timed_out"))?;
                 let shard_stats = shard_stats.ok_or_else(|| de::Error::missing_field("_shards"))?;
                 let aggs = aggs.unwrap_or_default();
+                let count = hits.total.unwrap_or(ResultCount::AtLeast(hits.hits.len()));
 
                 Ok(SearchResults {
                     search_time: took,
-                    count: hits.total,
+                    count,
                     max_score: hits.max_score,
                     timed_out,
                     shard_stats,
                     hits: hits.hits,
                     aggs,
                     scroll_id,
+                    pit_id,
+                    profile,
                 })
             }
         }
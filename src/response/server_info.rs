@@ -0,0 +1,61 @@
+use serde::Deserialize;
+
+/// Details the server reports about itself from
+/// the root endpoint of the cluster.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ServerInfo {
+    /// name of the node which answered
+    pub name: String,
+
+    /// name of the cluster the node belongs to
+    pub cluster_name: String,
+
+    /// unique id of the cluster
+    #[serde(default)]
+    pub cluster_uuid: Option<String>,
+
+    /// version details of the server
+    pub version: ServerVersion,
+}
+
+/// Version details of the server, which can be
+/// either Elasticsearch or OpenSearch.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ServerVersion {
+    /// full version number, such as `"7.17.4"`
+    pub number: String,
+
+    /// only reported by OpenSearch, which sets it to `"opensearch"`
+    #[serde(default)]
+    pub distribution: Option<String>,
+
+    /// oldest version the server can communicate with
+    #[serde(default)]
+    pub minimum_wire_compatibility_version: Option<String>,
+}
+
+impl ServerVersion {
+    /// Elasticsearch of the version number, such as `"7.17.4"`
+    pub fn new<S: Into<String>>(number: S) -> Self {
+        Self {
+            number: number.into(),
+            distribution: None,
+            minimum_wire_compatibility_version: None,
+        }
+    }
+
+    /// true if the server is OpenSearch instead of Elasticsearch
+    pub fn is_opensearch(&self) -> bool {
+        self.distribution.as_deref() == Some("opensearch")
+    }
+
+    /// the major version number, if it can be parsed
+    pub fn major(&self) -> Option<u16> {
+        self.number.split('.').next()?.parse().ok()
+    }
+
+    /// the minor version number, if it can be parsed
+    pub fn minor(&self) -> Option<u16> {
+        self.number.split('.').nth(1)?.parse().ok()
+    }
+}
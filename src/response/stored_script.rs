@@ -0,0 +1,10 @@
+use crate::request::StoredScript;
+use serde::Deserialize;
+
+/// Response from fetching a stored script, which
+/// has no script when it could not be found.
+#[derive(Debug, Deserialize)]
+pub(crate) struct FetchedScript {
+    #[serde(default)]
+    pub(crate) script: Option<StoredScript>,
+}
@@ -0,0 +1,151 @@
+use serde::Deserialize;
+use std::time::Duration;
+
+/// Where a long running task, such as a reindex or delete by
+/// query started without waiting for completion, is at.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TaskStatus {
+    /// true once the task has finished, successfully or not
+    pub completed: bool,
+
+    /// details of the task itself
+    pub task: TaskInfo,
+
+    /// why the task failed, if it did
+    #[serde(default)]
+    pub error: Option<TaskError>,
+
+    /// what the task responded with once completed, which
+    /// is the same as it would have if it had been waited on
+    #[serde(default)]
+    pub response: Option<serde_json::Value>,
+}
+
+impl TaskStatus {
+    /// how long the task has been running, or ran for
+    pub fn running_time(&self) -> Duration {
+        Duration::from_nanos(self.task.running_time_in_nanos)
+    }
+
+    /// counts of the work done so far, for the
+    /// tasks which report them
+    pub fn progress(&self) -> Option<&TaskProgress> {
+        self.task.status.as_ref()
+    }
+}
+
+/// A task as it is tracked by the node running it
+#[derive(Debug, Clone, Deserialize)]
+pub struct TaskInfo {
+    /// id of the node the task is running on
+    pub node: String,
+
+    /// id of the task on it's node
+    pub id: u64,
+
+    /// kind of work, such as `indices:data/write/reindex`
+    pub action: String,
+
+    /// human readable summary of the task
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// when the task started as milliseconds since the epoch
+    pub start_time_in_millis: u64,
+
+    /// how long the task has been running in nanoseconds
+    pub running_time_in_nanos: u64,
+
+    /// if the task can be cancelled
+    pub cancellable: bool,
+
+    /// if the task has been asked to cancel
+    #[serde(default)]
+    pub cancelled: bool,
+
+    /// counts of the work done so far
+    #[serde(default)]
+    pub status: Option<TaskProgress>,
+}
+
+/// Counts reported by reindex, update by query and
+/// delete by query tasks as they work through documents
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub struct TaskProgress {
+    /// documents the task will work through
+    #[serde(default)]
+    pub total: u64,
+
+    /// documents created
+    #[serde(default)]
+    pub created: u64,
+
+    /// documents updated
+    #[serde(default)]
+    pub updated: u64,
+
+    /// documents deleted
+    #[serde(default)]
+    pub deleted: u64,
+
+    /// documents left alone, such as by a script
+    #[serde(default)]
+    pub noops: u64,
+
+    /// scroll responses worked through
+    #[serde(default)]
+    pub batches: u64,
+
+    /// documents skipped because they changed underneath the task
+    #[serde(default)]
+    pub version_conflicts: u64,
+
+    /// how many times the task had to retry
+    #[serde(default)]
+    pub retries: TaskRetries,
+}
+
+impl TaskProgress {
+    /// documents the task is done with
+    pub fn processed(&self) -> u64 {
+        self.created + self.updated + self.deleted + self.noops + self.version_conflicts
+    }
+
+    /// ratio of documents done, from `0.0` to `1.0`, which
+    /// is unknown until the task has counted the total
+    pub fn ratio(&self) -> Option<f64> {
+        if self.total == 0 {
+            None
+        } else {
+            Some(self.processed() as f64 / self.total as f64)
+        }
+    }
+}
+
+/// Retries a task has made
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub struct TaskRetries {
+    /// bulk writes which were retried
+    #[serde(default)]
+    pub bulk: u64,
+
+    /// scroll searches which were retried
+    #[serde(default)]
+    pub search: u64,
+}
+
+/// Why a task failed
+#[derive(Debug, Clone, Deserialize)]
+pub struct TaskError {
+    /// kind of error, such as `search_phase_execution_exception`
+    #[serde(rename = "type")]
+    pub kind: String,
+
+    /// explanation of the error
+    #[serde(default)]
+    pub reason: Option<String>,
+
+    /// error underneath this one, if any
+    #[serde(default)]
+    pub caused_by: Option<Box<TaskError>>,
+}
@@ -0,0 +1,51 @@
+use serde::Deserialize;
+
+/// A document along with the details needed to write it back
+/// only if nobody else has changed it in the meantime.  Turn it
+/// into [crate::request::WriteOptions] with `From` to do so.
+#[derive(Debug, Clone, Deserialize)]
+pub struct VersionedDocument<T> {
+    /// Elasticsearch document ID
+    #[serde(rename = "_id")]
+    pub id: String,
+
+    /// sequence number of the last change to the document
+    #[serde(rename = "_seq_no")]
+    pub seq_no: u64,
+
+    /// primary term of the last change to the document
+    #[serde(rename = "_primary_term")]
+    pub primary_term: u64,
+
+    /// how many times the document has been changed, or
+    /// the external version it was last written with
+    #[serde(rename = "_version")]
+    pub version: u64,
+
+    /// the document data
+    #[serde(rename = "_source")]
+    pub doc: T,
+}
+
+/// Outcome of writing a document
+#[derive(Debug, Clone, Deserialize)]
+pub struct WriteResult {
+    /// Elasticsearch document ID
+    #[serde(rename = "_id")]
+    pub id: String,
+
+    /// what happened, such as `created`, `updated` or `deleted`
+    pub result: String,
+
+    /// sequence number of this write
+    #[serde(rename = "_seq_no")]
+    pub seq_no: u64,
+
+    /// primary term of this write
+    #[serde(rename = "_primary_term")]
+    pub primary_term: u64,
+
+    /// version of the document after this write
+    #[serde(rename = "_version")]
+    pub version: u64,
+}
@@ -0,0 +1,60 @@
+use elastic_lens::response::{AsyncSearchHandle, ResultCount};
+use serde_json::{json, Value};
+
+fn handle(response: Value) -> AsyncSearchHandle<Value> {
+    serde_json::from_str(&response.to_string()).unwrap()
+}
+
+#[test]
+fn a_running_search_with_partial_results() {
+    let handle = handle(json!({
+        "id": "FmRldE8zREVEUzA2ZVpUeGs2ejJFUFEaMkZ5QTVrSTZSaVN3WlNFVmtlWHJsdzoxMDc=",
+        "is_partial": true,
+        "is_running": true,
+        "start_time_in_millis": 1583945890986u64,
+        "expiration_time_in_millis": 1584377890986u64,
+        "response": {
+            "took": 1122,
+            "timed_out": false,
+            "num_reduce_phases": 0,
+            "_shards": { "total": 5, "successful": 3, "skipped": 0, "failed": 0 },
+            "hits": {
+                "total": { "value": 157483, "relation": "gte" },
+                "max_score": null,
+                "hits": []
+            }
+        }
+    }));
+
+    assert!(handle.is_running());
+    assert!(handle.is_partial());
+    assert!(handle.id().is_some());
+    assert!(matches!(
+        handle.results().unwrap().count(),
+        ResultCount::AtLeast(157483)
+    ));
+}
+
+#[test]
+fn a_search_finished_before_returning() {
+    let handle = handle(json!({
+        "is_partial": false,
+        "is_running": false,
+        "start_time_in_millis": 1583945890986u64,
+        "expiration_time_in_millis": 1584377890986u64,
+        "response": {
+            "took": 12,
+            "timed_out": false,
+            "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+            "hits": {
+                "total": { "value": 1, "relation": "eq" },
+                "max_score": 1.0,
+                "hits": [{ "_id": "1", "_index": "inventory", "_score": 1.0, "_source": {} }]
+            }
+        }
+    }));
+
+    assert!(!handle.is_running());
+    assert_eq!(handle.id(), None);
+    assert_eq!(handle.results().unwrap().hits().count(), 1);
+}
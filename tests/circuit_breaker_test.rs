@@ -0,0 +1,153 @@
+use elastic_lens::client::{CircuitBreaker, CircuitBreakerConfig, CircuitState, ClientError};
+use std::time::Duration;
+
+fn breaker(open_duration: Duration) -> CircuitBreaker {
+    CircuitBreaker::new(CircuitBreakerConfig {
+        failure_rate: 0.5,
+        window_size: 4,
+        minimum_requests: 4,
+        open_duration,
+        half_open_probes: 2,
+    })
+}
+
+fn trip(breaker: &CircuitBreaker) {
+    for _ in 0..4 {
+        breaker.try_acquire().unwrap().record(false);
+    }
+}
+
+#[test]
+fn opens_once_the_failure_rate_is_reached() {
+    let breaker = breaker(Duration::from_secs(60));
+
+    for success in [true, false, true] {
+        breaker.try_acquire().unwrap().record(success);
+    }
+    assert_eq!(breaker.state(), CircuitState::Closed);
+
+    breaker.try_acquire().unwrap().record(false);
+    assert_eq!(breaker.state(), CircuitState::Open);
+    assert!(matches!(
+        breaker.try_acquire(),
+        Err(ClientError::CircuitOpen)
+    ));
+}
+
+#[test]
+fn closes_after_enough_successful_probes() {
+    let breaker = breaker(Duration::ZERO);
+
+    trip(&breaker);
+    assert_eq!(breaker.state(), CircuitState::HalfOpen);
+
+    let first = breaker.try_acquire().unwrap();
+    let second = breaker.try_acquire().unwrap();
+    assert!(matches!(
+        breaker.try_acquire(),
+        Err(ClientError::CircuitOpen)
+    ));
+
+    first.record(true);
+    second.record(true);
+    assert_eq!(breaker.state(), CircuitState::Closed);
+}
+
+#[test]
+fn a_failed_probe_opens_the_circuit_again() {
+    let breaker = breaker(Duration::ZERO);
+
+    trip(&breaker);
+
+    breaker.try_acquire().unwrap().record(false);
+    assert!(breaker.try_acquire().is_ok());
+    assert_eq!(breaker.state(), CircuitState::HalfOpen);
+}
+
+#[test]
+fn a_dropped_probe_hands_back_its_slot() {
+    let breaker = breaker(Duration::ZERO);
+
+    trip(&breaker);
+
+    let first = breaker.try_acquire().unwrap();
+    drop(breaker.try_acquire().unwrap());
+
+    let second = breaker.try_acquire().unwrap();
+    first.record(true);
+    second.record(true);
+    assert_eq!(breaker.state(), CircuitState::Closed);
+}
+
+#[test]
+fn results_from_before_a_state_change_are_ignored() {
+    let breaker = breaker(Duration::ZERO);
+
+    let slow = [
+        breaker.try_acquire().unwrap(),
+        breaker.try_acquire().unwrap(),
+    ];
+    trip(&breaker);
+    assert_eq!(breaker.state(), CircuitState::HalfOpen);
+
+    // started while closed, so they aren't probes
+    for permit in slow {
+        permit.record(true);
+    }
+    assert_eq!(breaker.state(), CircuitState::HalfOpen);
+
+    let probe = breaker.try_acquire().unwrap();
+    breaker.try_acquire().unwrap().record(false);
+
+    // the circuit opened again since this probe started
+    probe.record(true);
+    let _held = [
+        breaker.try_acquire().unwrap(),
+        breaker.try_acquire().unwrap(),
+    ];
+    assert!(breaker.try_acquire().is_err());
+}
+
+#[cfg(feature = "official_client")]
+mod common;
+
+#[cfg(feature = "official_client")]
+mod client {
+    use super::common::scripted_server;
+    use super::*;
+    use elastic_lens::client::{Client, ClientBuilder, DefaultAdapter};
+    use serde_json::Value;
+
+    fn client(host: &str) -> Client<DefaultAdapter> {
+        ClientBuilder::default()
+            .host(host)
+            .index("inventory")
+            .circuit_breaker(CircuitBreakerConfig {
+                failure_rate: 0.5,
+                window_size: 1,
+                minimum_requests: 1,
+                open_duration: Duration::ZERO,
+                half_open_probes: 1,
+            })
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn a_cancelled_probe_does_not_wedge_the_circuit() {
+        let host = scripted_server(vec![Some(500), None, Some(404)]).await;
+        let client = client(&host);
+
+        let failed: Result<Option<Value>, _> = client.get_by_id("1").await;
+        assert!(failed.is_err());
+        assert_eq!(client.circuit_state(), Some(CircuitState::HalfOpen));
+
+        let cancelled =
+            tokio::time::timeout(Duration::from_millis(200), client.get_by_id::<Value>("2")).await;
+        assert!(cancelled.is_err());
+
+        let probe: Option<Value> = client.get_by_id("3").await.unwrap();
+        assert!(probe.is_none());
+        assert_eq!(client.circuit_state(), Some(CircuitState::Closed));
+    }
+}
@@ -0,0 +1,138 @@
+#![cfg(feature = "official_client")]
+
+use elastic_lens::client::{BuilderError, ClientBuilder, ClientConfig, DefaultAdapter};
+use serde_json::json;
+
+fn builder_error(result: Result<ClientBuilder<DefaultAdapter>, BuilderError>) -> String {
+    match result {
+        Err(BuilderError::BadData(keys)) => keys,
+        other => panic!("expected bad data, got {other:?}"),
+    }
+}
+
+#[test]
+fn building_a_client_from_a_config() {
+    let config: ClientConfig = serde_json::from_value(json!({
+        "host": "http://localhost:9200",
+        "index": "inventory",
+        "username": "elastic",
+        "password": "secret",
+        "default_limit": 20
+    }))
+    .unwrap();
+
+    let builder = ClientBuilder::<DefaultAdapter>::from_config(config).unwrap();
+
+    assert!(builder.build().is_ok());
+}
+
+#[test]
+fn a_config_reports_every_bad_key() {
+    let config: ClientConfig = serde_json::from_value(json!({
+        "username": "elastic",
+        "api_key": "missing-the-colon"
+    }))
+    .unwrap();
+
+    assert_eq!(
+        builder_error(ClientBuilder::from_config(config)),
+        "HOST,INDEX,PASSWORD,API_KEY"
+    );
+}
+
+#[test]
+fn a_config_and_the_builder_report_keys_alike() {
+    let config = ClientConfig::default();
+    let from_config = builder_error(ClientBuilder::from_config(config));
+
+    let built = ClientBuilder::<DefaultAdapter>::default().build();
+    let Err(BuilderError::BadData(from_build)) = built else {
+        panic!("expected bad data");
+    };
+
+    assert_eq!(from_config, "HOST,INDEX");
+    assert_eq!(from_config, from_build);
+}
+
+#[test]
+fn debugging_a_config_hides_secrets() {
+    let config: ClientConfig = serde_json::from_value(json!({
+        "host": "http://localhost:9200",
+        "index": "inventory",
+        "username": "elastic",
+        "password": "hunter2",
+        "api_key": "key-id:key-secret"
+    }))
+    .unwrap();
+
+    let debugged = format!("{config:?}");
+
+    assert!(debugged.contains("elastic"));
+    assert!(!debugged.contains("hunter2"));
+    assert!(!debugged.contains("key-secret"));
+
+    let builder = ClientBuilder::<DefaultAdapter>::default()
+        .credentials("elastic", "hunter2")
+        .api_key("key-id", "key-secret");
+
+    let debugged = format!("{builder:?}");
+
+    assert!(debugged.contains("key-id"));
+    assert!(!debugged.contains("hunter2"));
+    assert!(!debugged.contains("key-secret"));
+}
+
+#[test]
+fn a_config_rejects_unknown_keys() {
+    let result = serde_json::from_value::<ClientConfig>(json!({
+        "hots": "http://localhost:9200"
+    }));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn building_a_client_from_the_environment() {
+    std::env::set_var("ENV_TEST_ONE_HOST", "http://localhost:9200");
+    std::env::set_var("ENV_TEST_ONE_INDEX", "inventory");
+    #[cfg(not(feature = "opensearch"))]
+    std::env::set_var("ENV_TEST_ONE_API_KEY", "key-id:key-secret");
+    std::env::set_var("ENV_TEST_ONE_DEFAULT_LIMIT", "25");
+
+    let builder = ClientBuilder::<DefaultAdapter>::from_env("ENV_TEST_ONE").unwrap();
+
+    assert!(builder.build().is_ok());
+}
+
+#[cfg(feature = "opensearch")]
+#[test]
+fn opensearch_rejects_api_keys() {
+    std::env::set_var("ENV_TEST_THREE_HOST", "http://localhost:9200");
+    std::env::set_var("ENV_TEST_THREE_INDEX", "inventory");
+    std::env::set_var("ENV_TEST_THREE_API_KEY", "key-id:key-secret");
+
+    assert_eq!(
+        builder_error(ClientBuilder::from_env("ENV_TEST_THREE")),
+        "ENV_TEST_THREE_API_KEY"
+    );
+
+    let result = ClientBuilder::<DefaultAdapter>::default()
+        .host("http://localhost:9200")
+        .index("inventory")
+        .api_key("key-id", "key-secret")
+        .build();
+
+    assert!(matches!(result, Err(BuilderError::BadData(keys)) if keys == "API_KEY"));
+}
+
+#[test]
+fn the_environment_reports_every_bad_key() {
+    std::env::set_var("ENV_TEST_TWO_INDEX", "inventory");
+    std::env::set_var("ENV_TEST_TWO_PASSWORD", "secret");
+    std::env::set_var("ENV_TEST_TWO_DEFAULT_LIMIT", "twenty");
+
+    assert_eq!(
+        builder_error(ClientBuilder::from_env("ENV_TEST_TWO")),
+        "ENV_TEST_TWO_DEFAULT_LIMIT,ENV_TEST_TWO_HOST,ENV_TEST_TWO_USERNAME"
+    );
+}
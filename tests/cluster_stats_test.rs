@@ -0,0 +1,63 @@
+use elastic_lens::response::{ClusterHealth, HealthStatus, IndexStats};
+use serde_json::json;
+
+#[test]
+fn cluster_health_with_a_yellow_status() {
+    let health: ClusterHealth = serde_json::from_value(json!({
+        "cluster_name": "docker-cluster",
+        "status": "yellow",
+        "timed_out": false,
+        "number_of_nodes": 1,
+        "number_of_data_nodes": 1,
+        "active_primary_shards": 5,
+        "active_shards": 5,
+        "relocating_shards": 0,
+        "initializing_shards": 0,
+        "unassigned_shards": 5,
+        "delayed_unassigned_shards": 0,
+        "number_of_pending_tasks": 0,
+        "number_of_in_flight_fetch": 0,
+        "task_max_waiting_in_queue_millis": 0,
+        "active_shards_percent_as_number": 50.0
+    }))
+    .unwrap();
+
+    assert_eq!(health.status, HealthStatus::Yellow);
+    assert_eq!(health.unassigned_shards, 5);
+    assert!(!health.timed_out);
+}
+
+#[test]
+fn index_stats_with_shard_details() {
+    let totals = json!({
+        "docs": { "count": 42, "deleted": 2 },
+        "store": { "size_in_bytes": 1024, "reserved_in_bytes": 0 },
+        "indexing": { "index_total": 44 }
+    });
+
+    let stats: IndexStats = serde_json::from_value(json!({
+        "_shards": { "total": 2, "successful": 1, "failed": 0 },
+        "_all": { "primaries": totals, "total": totals },
+        "indices": {
+            "inventory": {
+                "uuid": "abc123",
+                "primaries": totals,
+                "total": totals,
+                "shards": {
+                    "0": [{
+                        "routing": { "state": "STARTED", "primary": true, "node": "n1" },
+                        "docs": { "count": 42, "deleted": 2 },
+                        "store": { "size_in_bytes": 1024 }
+                    }]
+                }
+            }
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(stats.all.primaries.docs.count, 42);
+
+    let inventory = &stats.indices["inventory"];
+    assert_eq!(inventory.total.store.size_in_bytes, 1024);
+    assert!(inventory.shards["0"][0].routing.primary);
+}
@@ -0,0 +1,78 @@
+//! A fake Elasticsearch server for the client tests, which
+//! answers with canned responses over a local socket
+#![allow(dead_code)]
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+/// Answers a single request with the status and body
+/// and hands back the request line it was sent
+pub async fn record_one_request(status: u16, body: &str) -> (String, JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let host = format!("http://{}", listener.local_addr().unwrap());
+    let body = body.to_owned();
+
+    let handle = tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let request_line = read_request_line(&mut stream).await;
+        respond(&mut stream, status, &body).await;
+        request_line
+    });
+
+    (host, handle)
+}
+
+/// Answers each request with the next status and an empty
+/// body, where `None` never answers at all
+pub async fn scripted_server(statuses: Vec<Option<u16>>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let host = format!("http://{}", listener.local_addr().unwrap());
+
+    tokio::spawn(async move {
+        let mut hung = vec![];
+
+        for status in statuses {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            read_request_line(&mut stream).await;
+
+            match status {
+                Some(status) => respond(&mut stream, status, "{}").await,
+                None => hung.push(stream),
+            }
+        }
+    });
+
+    host
+}
+
+/// Reads the head of a request, up to the blank line or
+/// until the client hangs up, and returns its first line
+pub async fn read_request_line(stream: &mut TcpStream) -> String {
+    let mut request = vec![];
+    let mut buffer = [0; 1024];
+
+    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+        let read = stream.read(&mut buffer).await.unwrap();
+
+        if read == 0 {
+            break;
+        }
+
+        request.extend_from_slice(&buffer[..read]);
+    }
+
+    let request = String::from_utf8_lossy(&request);
+    request.lines().next().unwrap_or_default().to_owned()
+}
+
+/// Writes a JSON response which closes the connection
+pub async fn respond(stream: &mut TcpStream, status: u16, body: &str) {
+    let response = format!(
+        "HTTP/1.1 {status} Whatever\r\ncontent-type: application/json\r\n\
+         x-elastic-product: Elasticsearch\r\ncontent-length: {}\r\n\
+         connection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes()).await.unwrap();
+}
@@ -0,0 +1,179 @@
+use elastic_lens::prelude::*;
+use elastic_lens::request::search::{DateMath, DateUnit, ScalarValue, SearchTrait};
+use serde_json::json;
+
+fn search_to_json(search: Search) -> serde_json::Value {
+    serde_json::to_value(search.search_body()).unwrap()
+}
+
+#[test]
+fn date_math_relative_to_now() {
+    assert_eq!(DateMath::now().to_string(), "now");
+
+    assert_eq!(
+        DateMath::now()
+            .minus(7, DateUnit::Days)
+            .rounded_to(DateUnit::Days)
+            .to_string(),
+        "now-7d/d"
+    );
+
+    assert_eq!(
+        DateMath::now()
+            .plus(1, DateUnit::Hours)
+            .minus(30, DateUnit::Minutes)
+            .to_string(),
+        "now+1h-30m"
+    );
+}
+
+#[test]
+fn subtracting_the_smallest_amount_does_not_overflow() {
+    assert_eq!(
+        DateMath::now().minus(i64::MIN, DateUnit::Days).to_string(),
+        "now+9223372036854775808d"
+    );
+}
+
+#[test]
+fn date_math_relative_to_an_anchor() {
+    assert_eq!(
+        DateMath::anchor("2024-01-31")
+            .plus(1, DateUnit::Months)
+            .rounded_to(DateUnit::Days)
+            .to_string(),
+        "2024-01-31||+1M/d"
+    );
+
+    assert_eq!(
+        DateMath::anchor(1_700_000_000_000i64)
+            .rounded_to(DateUnit::Years)
+            .to_string(),
+        "1700000000000||/y"
+    );
+
+    assert_eq!(
+        ScalarValue::from(DateMath::now().minus(1, DateUnit::Weeks)),
+        ScalarValue::from("now-1w")
+    );
+}
+
+#[test]
+fn a_date_range_with_format_and_time_zone() {
+    let mut search = Search::default();
+    search.with(
+        field("created_at")
+            .between("2024-01-01".."2024-01-31")
+            .format("yyyy-MM-dd")
+            .time_zone("America/New_York"),
+    );
+    search.with(
+        field("updated_at").greater_than(
+            DateMath::now()
+                .minus(1, DateUnit::Days)
+                .rounded_to(DateUnit::Hours),
+        ),
+    );
+
+    assert_eq!(
+        search_to_json(search),
+        json!({
+            "query": {
+                "bool": {
+                    "filter": [
+                        {
+                            "range": {
+                                "created_at": {
+                                    "gte": "2024-01-01",
+                                    "lte": "2024-01-31",
+                                    "format": "yyyy-MM-dd",
+                                    "time_zone": "America/New_York"
+                                }
+                            }
+                        },
+                        {
+                            "range": {
+                                "updated_at": {
+                                    "gt": "now-1d/h"
+                                }
+                            }
+                        }
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono_dates_are_scalar_values() {
+    use chrono::{NaiveDate, TimeZone, Utc};
+
+    let date = NaiveDate::from_ymd_opt(2024, 3, 9).unwrap();
+    let time = date.and_hms_milli_opt(8, 30, 0, 250).unwrap();
+
+    assert_eq!(ScalarValue::from(date), ScalarValue::from("2024-03-09"));
+    assert_eq!(
+        ScalarValue::from(time),
+        ScalarValue::from("2024-03-09T08:30:00.250")
+    );
+    assert_eq!(
+        ScalarValue::from(Utc.from_utc_datetime(&time)),
+        ScalarValue::from("2024-03-09T08:30:00.250Z")
+    );
+    assert_eq!(
+        DateMath::anchor(date).plus(1, DateUnit::Days).to_string(),
+        "2024-03-09||+1d"
+    );
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn time_dates_are_scalar_values() {
+    use time::{Date, Month, Time};
+
+    let date = Date::from_calendar_date(2024, Month::March, 9).unwrap();
+    let time = date.with_time(Time::from_hms_milli(8, 30, 0, 250).unwrap());
+
+    assert_eq!(ScalarValue::from(date), ScalarValue::from("2024-03-09"));
+    assert_eq!(
+        ScalarValue::from(time),
+        ScalarValue::from("2024-03-09T08:30:00.250")
+    );
+    assert_eq!(
+        ScalarValue::from(time.assume_utc()),
+        ScalarValue::from("2024-03-09T08:30:00.250Z")
+    );
+    assert_eq!(
+        ScalarValue::from(date.with_hms(8, 30, 0).unwrap()),
+        ScalarValue::from("2024-03-09T08:30:00")
+    );
+    assert_eq!(
+        ScalarValue::from(
+            date.with_hms_micro(8, 30, 0, 5)
+                .unwrap()
+                .assume_offset(time::UtcOffset::from_hms(-5, -30, 0).unwrap())
+        ),
+        ScalarValue::from("2024-03-09T08:30:00.000005-05:30")
+    );
+}
+
+#[cfg(all(feature = "chrono", feature = "time"))]
+#[test]
+fn chrono_and_time_send_the_same_text() {
+    let chrono_time = chrono::NaiveDate::from_ymd_opt(2024, 3, 9)
+        .unwrap()
+        .and_hms_micro_opt(8, 30, 0, 250)
+        .unwrap();
+    let time_time = time::Date::from_calendar_date(2024, time::Month::March, 9)
+        .unwrap()
+        .with_hms_micro(8, 30, 0, 250)
+        .unwrap();
+
+    assert_eq!(ScalarValue::from(chrono_time), ScalarValue::from(time_time));
+    assert_eq!(
+        ScalarValue::from(chrono_time.and_utc()),
+        ScalarValue::from(time_time.assume_utc())
+    );
+}
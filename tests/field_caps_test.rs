@@ -0,0 +1,112 @@
+use elastic_lens::prelude::*;
+use elastic_lens::request::search::{referenced_fields, FieldUsage};
+use elastic_lens::response::{FieldCaps, FieldIssue, FieldProblem};
+use serde_json::json;
+
+fn sample_search() -> Search {
+    let mut search = Search::default();
+    search.with(field("category").contains("clothing"));
+    search.with(nested("variants", |nested| {
+        nested.with(field("variants.size").contains("M"));
+    }));
+    search.sort(by_field("price").descending());
+    search
+        .create_aggregation("top-names")
+        .for_field("name")
+        .count_terms();
+    search.collapse_by_field("brand");
+    search
+}
+
+#[test]
+fn every_field_a_search_references() {
+    let search = sample_search();
+
+    let fields: Vec<_> = referenced_fields(&search)
+        .into_iter()
+        .map(|reference| (reference.field.name(), reference.usage))
+        .collect();
+
+    assert_eq!(
+        fields,
+        vec![
+            ("category", FieldUsage::Query),
+            ("variants", FieldUsage::Query),
+            ("variants.size", FieldUsage::Query),
+            ("price", FieldUsage::Sort),
+            ("name", FieldUsage::Aggregation),
+            ("brand", FieldUsage::Collapse),
+        ]
+    );
+}
+
+#[test]
+fn problems_with_the_fields_of_a_search() {
+    let caps: FieldCaps = serde_json::from_value(json!({
+        "indices": ["inventory-a", "inventory-b"],
+        "fields": {
+            "category": {
+                "keyword": { "type": "keyword", "searchable": true, "aggregatable": true }
+            },
+            "variants": {
+                "nested": { "type": "nested", "searchable": false, "aggregatable": false }
+            },
+            "variants.size": {
+                "keyword": { "type": "keyword", "searchable": true, "aggregatable": true }
+            },
+            "price": {
+                "long": {
+                    "type": "long", "searchable": true, "aggregatable": true,
+                    "indices": ["inventory-a"]
+                },
+                "float": {
+                    "type": "float", "searchable": true, "aggregatable": true,
+                    "indices": ["inventory-b"]
+                }
+            },
+            "name": {
+                "text": { "type": "text", "searchable": true, "aggregatable": false }
+            },
+            "brand": {
+                "keyword": {
+                    "type": "keyword", "searchable": true, "aggregatable": true,
+                    "indices": ["inventory-a"]
+                },
+                "unmapped": {
+                    "type": "unmapped", "searchable": false, "aggregatable": false,
+                    "indices": ["inventory-b"]
+                }
+            }
+        }
+    }))
+    .unwrap();
+
+    let mut search = sample_search();
+    search.with(field("colour").contains("blue"));
+
+    assert_eq!(
+        caps.problems(&search),
+        vec![
+            FieldProblem {
+                field: "colour".into(),
+                usage: FieldUsage::Query,
+                issue: FieldIssue::Unmapped,
+            },
+            FieldProblem {
+                field: "price".into(),
+                usage: FieldUsage::Sort,
+                issue: FieldIssue::TypeConflict(vec!["float".into(), "long".into()]),
+            },
+            FieldProblem {
+                field: "name".into(),
+                usage: FieldUsage::Aggregation,
+                issue: FieldIssue::NotAggregatable,
+            },
+            FieldProblem {
+                field: "brand".into(),
+                usage: FieldUsage::Collapse,
+                issue: FieldIssue::UnmappedIn(vec!["inventory-b".into()]),
+            },
+        ]
+    );
+}
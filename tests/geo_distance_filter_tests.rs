@@ -1,4 +1,7 @@
-use elastic_lens::request::search::{Distance, Field, GeoDistanceFilter, GeoPoint};
+use elastic_lens::request::search::{
+    Distance, DistanceError, DistanceUnit, Field, GeoDistanceFilter, GeoPoint, GeoPointError,
+};
+use serde_json::json;
 
 #[test]
 fn building_a_geo_distance_filter() {
@@ -11,3 +14,118 @@ fn building_a_geo_distance_filter() {
     assert!(matches!(filter.distance(), Distance::Miles(20)));
     assert_eq!(filter.point(), GeoPoint::new(42.8, 13.6));
 }
+
+#[test]
+fn fractional_distances_in_every_unit() {
+    let distance = |builder: elastic_lens::request::search::GeoDistanceFilter| {
+        serde_json::to_value(builder).unwrap()["geo_distance"]["distance"].clone()
+    };
+    let half = |unit| {
+        GeoDistanceFilter::with_field("loc")
+            .within_distance(Distance::new(0.5, unit).unwrap())
+            .unwrap()
+            .of(GeoPoint::new(1.0, 2.0))
+    };
+
+    assert_eq!(distance(half(DistanceUnit::Miles)), json!("0.5mi"));
+    assert_eq!(distance(half(DistanceUnit::Kilometers)), json!("0.5km"));
+    assert_eq!(distance(half(DistanceUnit::Meters)), json!("0.5m"));
+    assert_eq!(distance(half(DistanceUnit::Yards)), json!("0.5yd"));
+    assert_eq!(distance(half(DistanceUnit::Feet)), json!("0.5ft"));
+    assert_eq!(distance(half(DistanceUnit::NauticalMiles)), json!("0.5nmi"));
+
+    let filter = GeoDistanceFilter::with_field("loc")
+        .within(300)
+        .meters()
+        .of(GeoPoint::new(1.0, 2.0));
+
+    assert_eq!(filter.distance().amount(), 300.0);
+    assert_eq!(filter.distance().unit(), DistanceUnit::Meters);
+    assert_eq!(distance(filter), json!("300m"));
+}
+
+#[test]
+fn distances_must_be_finite_and_not_negative() {
+    assert_eq!(
+        Distance::new(-1, DistanceUnit::Miles),
+        Err(DistanceError::InvalidAmount(-1.0))
+    );
+    assert_eq!(
+        Distance::new(f64::INFINITY, DistanceUnit::Feet),
+        Err(DistanceError::InvalidAmount(f64::INFINITY))
+    );
+    assert!(Distance::new(f64::NAN, DistanceUnit::Meters).is_err());
+    assert_eq!(
+        Distance::new(0, DistanceUnit::Meters),
+        Ok(Distance::Measured(0.0, DistanceUnit::Meters))
+    );
+
+    let negative = Distance::Measured(-0.5, DistanceUnit::Miles);
+    assert!(GeoDistanceFilter::with_field("loc")
+        .within_distance(negative)
+        .is_err());
+    assert!(elastic_lens::request::search::field("loc")
+        .within_distance(Distance::Measured(f64::NAN, DistanceUnit::Feet))
+        .is_err());
+}
+
+#[test]
+fn parsing_geo_points_from_strings() {
+    assert_eq!(
+        "41.12,-71.34".parse::<GeoPoint>(),
+        Ok(GeoPoint::new(41.12, -71.34))
+    );
+
+    assert_eq!(
+        " POINT (-71.34 41.12) ".parse::<GeoPoint>(),
+        Ok(GeoPoint::new(41.12, -71.34))
+    );
+
+    let hashed: GeoPoint = "drm3btev3e86".parse().unwrap();
+    assert!((hashed.lat - 41.12).abs() < 0.0001);
+    assert!((hashed.lon - -71.34).abs() < 0.0001);
+
+    assert_eq!(
+        "91,0".parse::<GeoPoint>(),
+        Err(GeoPointError::LatitudeOutOfRange(91.0))
+    );
+
+    assert_eq!(
+        "POINT (181 0)".parse::<GeoPoint>(),
+        Err(GeoPointError::LongitudeOutOfRange(181.0))
+    );
+
+    assert!(matches!(
+        "not a point!".parse::<GeoPoint>(),
+        Err(GeoPointError::InvalidFormat(_))
+    ));
+}
+
+#[test]
+fn geo_points_from_lon_lat_arrays() {
+    assert_eq!(
+        GeoPoint::try_from([-71.34, 41.12]),
+        Ok(GeoPoint::new(41.12, -71.34))
+    );
+
+    assert_eq!(
+        GeoPoint::try_from([-200.0, 41.12]),
+        Err(GeoPointError::LongitudeOutOfRange(-200.0))
+    );
+}
+
+#[test]
+fn deserializing_geo_points_from_any_format() {
+    let points: Vec<GeoPoint> = serde_json::from_value(json!([
+        { "lat": 41.12, "lon": -71.34 },
+        [-71.34, 41.12],
+        "41.12,-71.34",
+        "POINT (-71.34 41.12)",
+    ]))
+    .unwrap();
+
+    assert!(points.iter().all(|p| *p == GeoPoint::new(41.12, -71.34)));
+
+    let invalid: Result<GeoPoint, _> = serde_json::from_value(json!({ "lat": 100, "lon": 0 }));
+    assert!(invalid.is_err());
+}
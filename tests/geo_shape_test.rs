@@ -0,0 +1,82 @@
+use elastic_lens::request::search::{GeoPoint, GeoShape, GeoShapeError};
+use serde_json::{json, to_value};
+
+#[test]
+fn a_polygon_with_a_hole_as_geojson() {
+    let shape = GeoShape::polygon_with_holes(
+        [
+            GeoPoint::new(0.0, 0.0),
+            GeoPoint::new(0.0, 10.0),
+            GeoPoint::new(10.0, 10.0),
+            GeoPoint::new(0.0, 0.0),
+        ],
+        [[
+            GeoPoint::new(1.0, 1.0),
+            GeoPoint::new(1.0, 2.0),
+            GeoPoint::new(2.0, 2.0),
+        ]],
+    );
+
+    assert_eq!(
+        to_value(&shape).unwrap(),
+        json!({
+            "type": "Polygon",
+            "coordinates": [
+                [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 0.0]],
+                [[1.0, 1.0], [2.0, 1.0], [2.0, 2.0], [1.0, 1.0]]
+            ]
+        })
+    );
+}
+
+#[test]
+fn shapes_read_from_geojson() {
+    let polygons: GeoShape = serde_json::from_value(json!({
+        "type": "MultiPolygon",
+        "coordinates": [
+            [[[102.0, 2.0], [103.0, 2.0], [103.0, 3.0], [102.0, 2.0]]],
+            [[[100.0, 0.0], [101.0, 0.0], [101.0, 1.0], [100.0, 0.0]]]
+        ]
+    }))
+    .unwrap();
+
+    let GeoShape::MultiPolygon(rings) = &polygons else {
+        panic!("expected a multi polygon, got {polygons:?}");
+    };
+    assert_eq!(rings.len(), 2);
+    assert_eq!(rings[0][0][1], GeoPoint::new(2.0, 103.0));
+
+    let point: GeoShape =
+        serde_json::from_value(json!({ "type": "Point", "coordinates": [-71.3, 41.1] })).unwrap();
+    assert_eq!(point, GeoShape::Point(GeoPoint::new(41.1, -71.3)));
+
+    let round_trip: GeoShape = serde_json::from_value(to_value(&polygons).unwrap()).unwrap();
+    assert_eq!(round_trip, polygons);
+
+    let line = serde_json::from_value::<GeoShape>(json!({
+        "type": "LineString",
+        "coordinates": [[0.0, 0.0], [1.0, 1.0]]
+    }));
+    assert!(line.is_err());
+}
+
+#[test]
+fn a_multi_polygon_only_takes_polygons() {
+    let square = GeoShape::polygon([
+        GeoPoint::new(0.0, 0.0),
+        GeoPoint::new(0.0, 1.0),
+        GeoPoint::new(1.0, 1.0),
+    ]);
+
+    let shape = GeoShape::multi_polygon([square.clone(), square.clone()]).unwrap();
+    let GeoShape::MultiPolygon(polygons) = shape else {
+        panic!("expected a multi polygon, got {shape:?}");
+    };
+    assert_eq!(polygons.len(), 2);
+
+    let point = GeoShape::Point(GeoPoint::new(5.0, 5.0));
+    assert_eq!(
+        GeoShape::multi_polygon([square, point]),
+        Err(GeoShapeError::NotAPolygon(1))
+    );
+}
@@ -0,0 +1,117 @@
+use elastic_lens::request::{
+    ComponentTemplate, FieldMapping, IndexSettings, IndexTemplate, Mappings,
+};
+use serde_json::{json, to_value};
+
+#[test]
+fn an_index_template_with_everything() {
+    let template = IndexTemplate::new(["orders-*"])
+        .priority(200)
+        .version(3)
+        .composed_of("base-settings")
+        .settings(IndexSettings::new().shards(2).refresh_every("30s"))
+        .mappings(
+            Mappings::new()
+                .set("dynamic", "strict")
+                .field("total", FieldMapping::double())
+                .field(
+                    "customer",
+                    FieldMapping::object().field(
+                        "name",
+                        FieldMapping::text().multi_field("raw", FieldMapping::keyword()),
+                    ),
+                ),
+        )
+        .alias("orders");
+
+    assert_eq!(
+        to_value(&template).unwrap(),
+        json!({
+            "index_patterns": ["orders-*"],
+            "composed_of": ["base-settings"],
+            "priority": 200,
+            "version": 3,
+            "template": {
+                "settings": {
+                    "number_of_shards": 2,
+                    "refresh_interval": "30s"
+                },
+                "mappings": {
+                    "dynamic": "strict",
+                    "properties": {
+                        "total": { "type": "double" },
+                        "customer": {
+                            "properties": {
+                                "name": {
+                                    "type": "text",
+                                    "fields": { "raw": { "type": "keyword" } }
+                                }
+                            }
+                        }
+                    }
+                },
+                "aliases": { "orders": {} }
+            }
+        })
+    );
+}
+
+#[test]
+fn an_index_template_as_the_server_returns_it() {
+    let template: IndexTemplate = serde_json::from_value(json!({
+        "index_patterns": ["orders-*"],
+        "composed_of": ["base-settings"],
+        "priority": 200,
+        "template": {
+            "settings": {
+                "index": {
+                    "number_of_shards": "2",
+                    "number_of_replicas": "1",
+                    "codec": "best_compression"
+                }
+            },
+            "mappings": {
+                "properties": {
+                    "total": { "type": "scaled_float", "scaling_factor": 100.0 }
+                }
+            },
+            "aliases": { "orders": {} }
+        }
+    }))
+    .unwrap();
+
+    let settings = template.get_settings().unwrap();
+    assert_eq!(settings.number_of_shards(), Some(2));
+    assert_eq!(settings.number_of_replicas(), Some(1));
+    assert_eq!(settings.get("codec"), Some(&json!("best_compression")));
+
+    let total = template.get_mappings().unwrap().property("total").unwrap();
+    assert_eq!(total.kind(), Some("scaled_float"));
+    assert_eq!(total.get("scaling_factor"), Some(&json!(100.0)));
+
+    assert_eq!(template.get_priority(), Some(200));
+    assert_eq!(template.components(), ["base-settings"]);
+    assert_eq!(template.aliases().collect::<Vec<_>>(), ["orders"]);
+}
+
+#[test]
+fn a_component_template() {
+    let component = ComponentTemplate::new()
+        .settings(IndexSettings::new().replicas(1))
+        .mappings(Mappings::new().field("created_at", FieldMapping::date()));
+
+    assert_eq!(
+        to_value(&component).unwrap(),
+        json!({
+            "template": {
+                "settings": { "number_of_replicas": 1 },
+                "mappings": {
+                    "properties": { "created_at": { "type": "date" } }
+                }
+            }
+        })
+    );
+
+    let parsed: ComponentTemplate = serde_json::from_value(to_value(&component).unwrap()).unwrap();
+    assert_eq!(parsed, component);
+}
@@ -0,0 +1,34 @@
+use elastic_lens::client::{Cache, LruCache};
+use std::time::Duration;
+
+#[test]
+fn returns_what_was_stored() {
+    let cache = LruCache::new(2, Duration::from_secs(60));
+    cache.insert("a".into(), "one".into());
+
+    assert_eq!(cache.get("a").as_deref(), Some("one"));
+    assert_eq!(cache.get("b"), None);
+}
+
+#[test]
+fn evicts_the_least_recently_used() {
+    let cache = LruCache::new(2, Duration::from_secs(60));
+    cache.insert("a".into(), "one".into());
+    cache.insert("b".into(), "two".into());
+    cache.get("a");
+    cache.insert("c".into(), "three".into());
+
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.get("b"), None);
+    assert_eq!(cache.get("a").as_deref(), Some("one"));
+    assert_eq!(cache.get("c").as_deref(), Some("three"));
+}
+
+#[test]
+fn expired_entries_are_not_returned() {
+    let cache = LruCache::new(2, Duration::ZERO);
+    cache.insert("a".into(), "one".into());
+
+    assert_eq!(cache.get("a"), None);
+    assert!(cache.is_empty());
+}
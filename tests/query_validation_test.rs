@@ -0,0 +1,42 @@
+use elastic_lens::response::QueryValidation;
+use serde_json::json;
+
+#[test]
+fn a_valid_query() {
+    let validation: QueryValidation = serde_json::from_value(json!({
+        "_shards": { "total": 1, "successful": 1, "failed": 0 },
+        "valid": true,
+        "explanations": [{
+            "index": "inventory",
+            "valid": true,
+            "explanation": "+category:clothing #*:*"
+        }]
+    }))
+    .unwrap();
+
+    assert!(validation.valid);
+    assert_eq!(
+        validation.explanations[0].explanation.as_deref(),
+        Some("+category:clothing #*:*")
+    );
+    assert_eq!(validation.errors().count(), 0);
+}
+
+#[test]
+fn an_invalid_query() {
+    let validation: QueryValidation = serde_json::from_value(json!({
+        "valid": false,
+        "explanations": [{
+            "index": "inventory",
+            "valid": false,
+            "error": "failed to create query: For input string: \"abc\""
+        }]
+    }))
+    .unwrap();
+
+    assert!(!validation.valid);
+    assert_eq!(
+        validation.errors().collect::<Vec<_>>(),
+        vec!["failed to create query: For input string: \"abc\""]
+    );
+}
@@ -0,0 +1,113 @@
+#![cfg(feature = "official_client")]
+
+use elastic_lens::client::{Client, ClientBuilder, DefaultAdapter};
+use elastic_lens::request::WriteOptions;
+use elastic_lens::response::{ServerVersion, VersionedDocument};
+use serde_json::{json, Value};
+
+mod common;
+
+use common::record_one_request;
+
+fn client(host: &str) -> Client<DefaultAdapter> {
+    ClientBuilder::default()
+        .host(host)
+        .index("inventory")
+        .assume_server_version(ServerVersion::new("8.11.0"))
+        .build()
+        .unwrap()
+}
+
+fn document() -> Value {
+    json!({
+        "_index": "inventory",
+        "_id": "7",
+        "_version": 2,
+        "_seq_no": 3,
+        "_primary_term": 1,
+        "found": true,
+        "_source": { "name": "answer" }
+    })
+}
+
+fn written(result: &str) -> Value {
+    json!({
+        "_index": "inventory",
+        "_id": "7",
+        "result": result,
+        "_version": 3,
+        "_seq_no": 4,
+        "_primary_term": 1
+    })
+}
+
+#[tokio::test]
+async fn reading_with_a_routing() {
+    let (host, request) = record_one_request(200, &document().to_string()).await;
+
+    let doc: Option<Value> = client(&host)
+        .get_by_id_routed("7", "question-1")
+        .await
+        .unwrap();
+
+    assert_eq!(doc.unwrap()["name"], "answer");
+    assert!(request.await.unwrap().contains("routing=question-1"));
+}
+
+#[tokio::test]
+async fn reading_versioned_with_a_routing() {
+    let (host, request) = record_one_request(200, &document().to_string()).await;
+
+    let doc: VersionedDocument<Value> = client(&host)
+        .get_versioned_by_id_routed("7", "question-1")
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(doc.seq_no, 3);
+    assert!(request.await.unwrap().contains("routing=question-1"));
+}
+
+#[tokio::test]
+async fn reading_without_a_routing() {
+    let (host, request) = record_one_request(200, &document().to_string()).await;
+
+    let _: Option<Value> = client(&host).get_by_id("7").await.unwrap();
+
+    assert!(!request.await.unwrap().contains("routing="));
+}
+
+#[tokio::test]
+async fn the_routing_survives_a_sequence_number_condition() {
+    let (host, request) = record_one_request(200, &written("updated").to_string()).await;
+    let options = WriteOptions::new().routing("question-1").if_seq_no(3, 1);
+
+    client(&host)
+        .index_document("7", &json!({ "name": "answer" }), &options)
+        .await
+        .unwrap();
+
+    let request = request.await.unwrap();
+    assert!(request.contains(" /inventory/_doc/7?"));
+    assert!(request.contains("routing=question-1"));
+    assert!(request.contains("if_seq_no=3"));
+    assert!(request.contains("if_primary_term=1"));
+}
+
+#[tokio::test]
+async fn the_routing_survives_an_external_version() {
+    let (host, request) = record_one_request(200, &written("deleted").to_string()).await;
+    let options = WriteOptions::new()
+        .external_version(9)
+        .routing("question-1");
+
+    let result = client(&host).delete_document("7", &options).await.unwrap();
+
+    assert_eq!(result.unwrap().result, "deleted");
+
+    let request = request.await.unwrap();
+    assert!(request.starts_with("DELETE /inventory/_doc/7?"));
+    assert!(request.contains("routing=question-1"));
+    assert!(request.contains("version=9"));
+    assert!(request.contains("version_type=external"));
+}
@@ -0,0 +1,176 @@
+use elastic_lens::response::{ResultCount, SearchResults};
+use serde_json::{json, Value};
+
+fn results(hits: Value) -> SearchResults<Value> {
+    let response = json!({
+        "took": 3,
+        "timed_out": false,
+        "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+        "hits": hits
+    });
+
+    serde_json::from_str(&response.to_string()).unwrap()
+}
+
+fn hit(id: &str) -> Value {
+    json!({ "_id": id, "_index": "inventory", "_score": 1.0, "_source": {} })
+}
+
+#[test]
+fn a_total_with_a_relation() {
+    let results = results(json!({
+        "total": { "value": 10000, "relation": "gte" },
+        "max_score": 1.0,
+        "hits": [hit("1")]
+    }));
+
+    assert!(matches!(results.count(), ResultCount::AtLeast(10000)));
+}
+
+#[test]
+fn a_total_as_a_plain_number() {
+    let results = results(json!({
+        "total": 42,
+        "max_score": 1.0,
+        "hits": [hit("1")]
+    }));
+
+    assert!(matches!(results.count(), ResultCount::Exactly(42)));
+}
+
+#[test]
+fn an_untracked_total_counts_the_hits() {
+    let results = results(json!({
+        "max_score": 1.0,
+        "hits": [hit("1"), hit("2")]
+    }));
+
+    assert!(matches!(results.count(), ResultCount::AtLeast(2)));
+}
+
+#[test]
+fn hits_with_an_explanation() {
+    let mut explained = hit("1");
+    explained["_explanation"] = json!({
+        "value": 1.6,
+        "description": "weight(name:shirt in 0)",
+        "details": [
+            { "value": 2.2, "description": "boost", "details": [] }
+        ]
+    });
+
+    let results = results(json!({
+        "total": 1,
+        "max_score": 1.6,
+        "hits": [explained]
+    }));
+
+    let explanation = results.hits().next().unwrap().explanation.as_ref().unwrap();
+    assert_eq!(explanation.description, "weight(name:shirt in 0)");
+    assert_eq!(explanation.details[0].value, 2.2);
+}
+
+#[test]
+fn hits_with_percolator_document_slots() {
+    let mut matched = hit("1");
+    matched["fields"] = json!({ "_percolator_document_slot": [0, 2] });
+
+    let results = results(json!({
+        "total": 2,
+        "max_score": 1.0,
+        "hits": [matched, hit("2")]
+    }));
+
+    let hits: Vec<_> = results.hits().collect();
+    assert_eq!(hits[0].percolator_document_slots, vec![0, 2]);
+    assert!(hits[1].percolator_document_slots.is_empty());
+}
+
+#[test]
+fn hits_with_named_inner_hits() {
+    #[derive(serde::Deserialize)]
+    struct Review {
+        stars: u8,
+    }
+
+    let mut parent = hit("1");
+    parent["inner_hits"] = json!({
+        "top_reviews": {
+            "hits": {
+                "total": { "value": 3, "relation": "eq" },
+                "max_score": 2.5,
+                "hits": [
+                    {
+                        "_index": "inventory",
+                        "_id": "1",
+                        "_nested": { "field": "reviews", "offset": 2 },
+                        "_score": 2.5,
+                        "_source": { "stars": 5 }
+                    }
+                ]
+            }
+        },
+        "answers": {
+            "hits": { "max_score": null, "hits": [] }
+        }
+    });
+
+    let results = results(json!({
+        "total": 2,
+        "max_score": 1.0,
+        "hits": [parent, hit("2")]
+    }));
+
+    let hits: Vec<_> = results.hits().collect();
+    let reviews = &hits[0].inner_hits["top_reviews"];
+
+    assert!(matches!(reviews.count, Some(ResultCount::Exactly(3))));
+    assert_eq!(reviews.max_score, Some(2.5));
+    assert_eq!(reviews.hits[0].id.as_deref(), Some("1"));
+
+    let nested = reviews.hits[0].nested.as_ref().unwrap();
+    assert_eq!((nested.field.as_str(), nested.offset), ("reviews", 2));
+    assert_eq!(reviews.hits[0].doc_as::<Review>().unwrap().stars, 5);
+
+    assert!(hits[0].inner_hits["answers"].hits.is_empty());
+    assert!(hits[1].inner_hits.is_empty());
+}
+
+#[test]
+fn a_profile_and_unknown_fields() {
+    let response = json!({
+        "took": 3,
+        "timed_out": false,
+        "num_reduce_phases": 1,
+        "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+        "hits": { "total": 0, "max_score": null, "hits": [] },
+        "profile": {
+            "shards": [{
+                "id": "[node][inventory][0]",
+                "searches": [{
+                    "query": [{
+                        "type": "TermQuery",
+                        "description": "name:shirt",
+                        "time_in_nanos": 1500,
+                        "breakdown": { "score": 500, "score_count": 2 }
+                    }],
+                    "rewrite_time": 100,
+                    "collector": [{
+                        "name": "SimpleTopScoreDocCollector",
+                        "reason": "search_top_hits",
+                        "time_in_nanos": 800
+                    }]
+                }],
+                "aggregations": []
+            }]
+        }
+    });
+
+    let results: SearchResults<Value> = serde_json::from_str(&response.to_string()).unwrap();
+    let shard = &results.profile().unwrap().shards[0];
+    let query = &shard.searches[0].query[0];
+
+    assert_eq!(query.kind, "TermQuery");
+    assert_eq!(query.breakdown["score_count"], 2);
+    assert_eq!(shard.searches[0].collector[0].time_in_nanos, 800);
+}
@@ -278,6 +278,7 @@ fn a_search_with_geo_distance_in_miles() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn a_search_with_geo_distance_in_kilometers_in_not() {
     use elastic_lens::request::search::GeoPoint;
 
@@ -696,6 +697,49 @@ fn building_a_search_with_a_script_score_and_params() {
     );
 }
 
+#[test]
+fn building_a_search_with_a_stored_script_score() {
+    let mut search = Search::default();
+    search.sort(by_stored_script_score("score-inventory").with_params([("spice", 42)]));
+
+    assert_eq!(
+        search_to_json(search),
+        json!({
+            "query": {
+                "script_score": {
+                    "script": {
+                        "id": "score-inventory",
+                        "params": {
+                            "spice": 42
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn building_a_stored_script_sort() {
+    let mut search = Search::default();
+    search.sort(by_stored_script("rank-inventory").descending());
+
+    assert_eq!(
+        search_to_json(search),
+        json!({
+            "sort": [
+                { "_script": {
+                    "script": {
+                        "id": "rank-inventory"
+                    },
+                    "type": "number",
+                    "order": "desc"
+                } }
+            ]
+        })
+    );
+}
+
 #[test]
 fn building_a_search_with_an_empty_all_match() {
     use elastic_lens::request::search::AllMatch;
@@ -811,3 +855,912 @@ fn collapse_by_field_and_max_concurrent() {
         })
     );
 }
+
+#[test]
+fn a_search_without_tracking_total_hits() {
+    let mut search = Search::default();
+    search.set_track_total_hits(false);
+
+    assert_eq!(
+        search_to_json(search),
+        json!({
+            "track_total_hits": false
+        })
+    );
+}
+
+#[test]
+fn a_search_with_explain_and_profile() {
+    let mut search = Search::default();
+    search.set_explain(true);
+    search.set_profile(true);
+
+    assert_eq!(
+        search_to_json(search),
+        json!({
+            "explain": true,
+            "profile": true
+        })
+    );
+}
+
+#[test]
+fn a_search_as_a_percolator_query() {
+    let mut search = Search::default();
+    search.with(field("category").contains("clothing"));
+
+    assert_eq!(
+        serde_json::to_value(search.percolator_query()).unwrap(),
+        json!({
+            "bool": {
+                "filter": [
+                    { "term": { "category": "clothing" } }
+                ]
+            }
+        })
+    );
+
+    let empty = Search::default();
+
+    assert_eq!(
+        serde_json::to_value(empty.percolator_query()).unwrap(),
+        json!({ "match_all": {} })
+    );
+}
+
+#[test]
+fn a_search_percolating_documents() {
+    use elastic_lens::request::search::PercolateFilter;
+
+    #[derive(serde::Serialize)]
+    struct Product {
+        name: &'static str,
+    }
+
+    let mut search = Search::default();
+    search.with(field("query").percolates([json!({ "name": "shirt" })]));
+    search.with(
+        PercolateFilter::from_documents(
+            "saved",
+            [Product { name: "hat" }, Product { name: "scarf" }],
+        )
+        .unwrap(),
+    );
+
+    assert_eq!(
+        search_to_json(search),
+        json!({
+            "query": {
+                "bool": {
+                    "filter": [
+                        { "percolate": { "field": "query", "documents": [{ "name": "shirt" }] } },
+                        { "percolate": {
+                            "field": "saved",
+                            "documents": [{ "name": "hat" }, { "name": "scarf" }]
+                        } }
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn a_search_with_full_text_matches() {
+    use elastic_lens::request::search::{Fuzziness, MatchFilter, MatchPhraseFilter, Operator};
+
+    let mut search = Search::default();
+    search.with(field("title").matches("red shirt"));
+    search.with(field("title").matches_phrase("long sleeve"));
+    search.with(field("brand").matches_phrase_prefix("north fa"));
+    search.with(
+        MatchFilter::new("description", "cotton blend")
+            .operator(Operator::And)
+            .fuzziness(Fuzziness::Auto),
+    );
+    search.with(MatchPhraseFilter::new("description", "machine washable").slop(2));
+
+    assert_eq!(
+        search_to_json(search),
+        json!({
+            "query": {
+                "bool": {
+                    "filter": [
+                        { "match": { "title": { "query": "red shirt" } } },
+                        { "match_phrase": { "title": { "query": "long sleeve" } } },
+                        { "match_phrase_prefix": { "brand": { "query": "north fa" } } },
+                        { "match": { "description": {
+                            "query": "cotton blend",
+                            "operator": "and",
+                            "fuzziness": "AUTO"
+                        } } },
+                        { "match_phrase": { "description": {
+                            "query": "machine washable",
+                            "slop": 2
+                        } } }
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn a_search_with_a_multi_match() {
+    use elastic_lens::request::search::{Fuzziness, MultiMatchType, Operator};
+
+    let mut search = Search::default();
+    search.with(
+        multi_match("red shirt")
+            .boosted_field("title", 3.0)
+            .boosted_field("subtitle", 1.5)
+            .field("description")
+            .match_type(MultiMatchType::MostFields)
+            .operator(Operator::Or)
+            .fuzziness(Fuzziness::Edits(1)),
+    );
+
+    assert_eq!(
+        search_to_json(search),
+        json!({
+            "query": {
+                "bool": {
+                    "filter": [
+                        { "multi_match": {
+                            "query": "red shirt",
+                            "fields": ["title^3", "subtitle^1.5", "description"],
+                            "type": "most_fields",
+                            "operator": "or",
+                            "fuzziness": 1
+                        } }
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn a_search_with_scoring_criteria() {
+    let mut search = Search::default();
+    search.with(field("in_stock").contains(true));
+    search.score_with(field("title").matches("red shirt"));
+    search.score_with(!field("title").matches("used"));
+    search.should_score_with(field("brand").contains("acme"));
+    search.should_score_with(field("on_sale").contains(true));
+    search.set_minimum_should_match(1);
+    search.set_boost(2.0);
+
+    assert_eq!(
+        search_to_json(search),
+        json!({
+            "query": {
+                "bool": {
+                    "filter": [
+                        { "term": { "in_stock": true } }
+                    ],
+                    "must": [
+                        { "match": { "title": { "query": "red shirt" } } }
+                    ],
+                    "must_not": [
+                        { "match": { "title": { "query": "used" } } }
+                    ],
+                    "should": [
+                        { "term": { "brand": "acme" } },
+                        { "term": { "on_sale": true } }
+                    ],
+                    "minimum_should_match": 1,
+                    "boost": 2.0
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn a_boost_is_kept_without_scoring_criteria() {
+    let mut search = Search::default();
+    search.with(field("in_stock").contains(true));
+    search.set_boost(2.0);
+
+    assert_eq!(
+        search_to_json(search),
+        json!({
+            "query": {
+                "bool": {
+                    "filter": [
+                        { "term": { "in_stock": true } }
+                    ],
+                    "boost": 2.0
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn a_boost_only_group_is_kept_as_a_bool() {
+    let mut search = Search::default();
+    search.with(field("a").contains(1));
+    search.with(if_all_match(|all| all.set_boost(2.0)));
+    search.with(has_child("line_item", |child| child.set_boost(2.0)));
+
+    assert_eq!(
+        search_to_json(search),
+        json!({
+            "query": {
+                "bool": {
+                    "filter": [
+                        { "term": { "a": 1 } },
+                        { "bool": { "boost": 2.0 } },
+                        {
+                            "has_child": {
+                                "type": "line_item",
+                                "query": { "bool": { "boost": 2.0 } }
+                            }
+                        }
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn an_all_match_with_scoring_criteria() {
+    let mut search = Search::default();
+    search.should_score_with(if_all_match(|all| {
+        all.with(field("category").contains("shirts"));
+        all.score_with(field("title").matches("linen"));
+        all.should_score_with(!field("color").contains("white"));
+        all.set_minimum_should_match("50%");
+    }));
+
+    assert_eq!(
+        search_to_json(search),
+        json!({
+            "query": {
+                "bool": {
+                    "should": [
+                        { "bool": {
+                            "filter": [
+                                { "term": { "category": "shirts" } }
+                            ],
+                            "must": [
+                                { "match": { "title": { "query": "linen" } } }
+                            ],
+                            "should": [
+                                { "bool": { "must_not": [
+                                    { "term": { "color": "white" } }
+                                ] } }
+                            ],
+                            "minimum_should_match": "50%"
+                        } }
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn a_search_with_term_level_patterns() {
+    use elastic_lens::request::search::Fuzziness;
+
+    let mut search = Search::default();
+    search.with(field("sku").starts_with("SHRT-"));
+    search.with(field("sku").wildcard("*-XL").case_insensitive());
+    search.with(!field("sku").regexp("[0-9]+"));
+    search.with(
+        field("brand")
+            .fuzzy("acme")
+            .fuzziness(Fuzziness::Edits(1))
+            .prefix_length(1)
+            .max_expansions(10),
+    );
+    search.with(field("color").contains_ignoring_case("Blue"));
+    search.with(!ids(["1", "2"]));
+
+    assert_eq!(
+        search_to_json(search),
+        json!({
+            "query": {
+                "bool": {
+                    "filter": [
+                        { "prefix": { "sku": { "value": "SHRT-" } } },
+                        { "wildcard": { "sku": { "value": "*-XL", "case_insensitive": true } } },
+                        { "fuzzy": { "brand": {
+                            "value": "acme",
+                            "fuzziness": 1,
+                            "prefix_length": 1,
+                            "max_expansions": 10
+                        } } },
+                        { "term": { "color": { "value": "Blue", "case_insensitive": true } } }
+                    ],
+                    "must_not": [
+                        { "regexp": { "sku": { "value": "[0-9]+" } } },
+                        { "ids": { "values": ["1", "2"] } }
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn term_level_patterns_in_nested_blocks() {
+    let mut search = Search::default();
+    search.with(if_any_match(|any| {
+        any.with(ids(["42"]));
+        any.with(!field("sku").starts_with("OLD-"));
+    }));
+    search.with(nested("variants", |nested| {
+        nested.with(field("variants.sku").wildcard("*-M"));
+    }));
+    search
+        .create_aggregation("fuzzy_brands")
+        .filtered_by(|filter| filter.with(field("brand").fuzzy("acme")));
+
+    assert_eq!(
+        search_to_json(search),
+        json!({
+            "query": {
+                "bool": {
+                    "filter": [
+                        { "bool": { "should": [
+                            { "ids": { "values": ["42"] } },
+                            { "bool": { "must_not": [
+                                { "prefix": { "sku": { "value": "OLD-" } } }
+                            ] } }
+                        ] } },
+                        { "nested": {
+                            "path": "variants",
+                            "query": { "bool": { "filter": [
+                                { "wildcard": { "variants.sku": { "value": "*-M" } } }
+                            ] } }
+                        } }
+                    ]
+                }
+            },
+            "aggs": {
+                "fuzzy_brands": {
+                    "filter": { "bool": { "filter": [
+                        { "fuzzy": { "brand": { "value": "acme" } } }
+                    ] } }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn a_nested_block_with_inner_hits() {
+    use elastic_lens::request::search::InnerHits;
+
+    let mut search = Search::default();
+    search.with(nested("reviews", |nested| {
+        nested.with(field("reviews.stars").greater_than_or_equal(4));
+        nested.set_inner_hits(InnerHits::new().name("top_reviews").size(3));
+    }));
+
+    assert_eq!(
+        search_to_json(search),
+        json!({
+            "query": {
+                "bool": {
+                    "filter": [
+                        { "nested": {
+                            "path": "reviews",
+                            "query": { "bool": { "filter": [
+                                { "range": { "reviews.stars": { "gte": 4 } } }
+                            ] } },
+                            "inner_hits": { "name": "top_reviews", "size": 3 }
+                        } }
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn a_search_with_query_strings() {
+    use elastic_lens::request::search::{Operator, SimpleQueryFlag};
+
+    let mut search = Search::default();
+    search.with(field("in_stock").contains(true));
+    search.score_with(
+        query_string("shirt AND (red OR blue)")
+            .boosted_field("title", 2.0)
+            .field("description")
+            .default_operator(Operator::And)
+            .lenient(true),
+    );
+    search.score_with(
+        simple_query_string("\"red shirt\" -used")
+            .field("title")
+            .analyzer("english")
+            .flags([SimpleQueryFlag::Phrase, SimpleQueryFlag::Not]),
+    );
+
+    assert_eq!(
+        search_to_json(search),
+        json!({
+            "query": {
+                "bool": {
+                    "filter": [
+                        { "term": { "in_stock": true } }
+                    ],
+                    "must": [
+                        { "query_string": {
+                            "query": "shirt AND (red OR blue)",
+                            "fields": ["title^2", "description"],
+                            "default_operator": "and",
+                            "lenient": true
+                        } },
+                        { "simple_query_string": {
+                            "query": "\"red shirt\" -used",
+                            "fields": ["title"],
+                            "analyzer": "english",
+                            "flags": "PHRASE|NOT"
+                        } }
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn a_search_with_geo_boxes_and_shapes() {
+    use elastic_lens::request::search::{GeoPoint, GeoShape, GeoShapeRelation};
+
+    let mut search = Search::default();
+    search.with(
+        field("location")
+            .within_bounding_box(GeoPoint::new(40.73, -74.1), GeoPoint::new(40.01, -71.12)),
+    );
+    search.with(field("location").within_polygon([
+        GeoPoint::new(40.0, -70.0),
+        GeoPoint::new(30.0, -80.0),
+        GeoPoint::new(20.0, -90.0),
+    ]));
+    search.with(!field("delivery_area").geo_shape(
+        GeoShapeRelation::Intersects,
+        GeoShape::envelope(GeoPoint::new(45.0, 13.0), GeoPoint::new(43.0, 14.0)),
+    ));
+
+    assert_eq!(
+        search_to_json(search),
+        json!({
+            "query": {
+                "bool": {
+                    "filter": [
+                        { "geo_bounding_box": { "location": {
+                            "top_left": { "lat": 40.73, "lon": -74.1 },
+                            "bottom_right": { "lat": 40.01, "lon": -71.12 }
+                        } } },
+                        { "geo_shape": { "location": {
+                            "shape": {
+                                "type": "Polygon",
+                                "coordinates": [[
+                                    [-70.0, 40.0], [-80.0, 30.0], [-90.0, 20.0], [-70.0, 40.0]
+                                ]]
+                            },
+                            "relation": "within"
+                        } } }
+                    ],
+                    "must_not": [
+                        { "geo_shape": { "delivery_area": {
+                            "shape": {
+                                "type": "envelope",
+                                "coordinates": [[13.0, 45.0], [14.0, 43.0]]
+                            },
+                            "relation": "intersects"
+                        } } }
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn a_search_with_a_function_score() {
+    use elastic_lens::request::search::{
+        BoostMode, DecayFunction, Distance, FieldValueFactor, GeoPoint, Modifier, RandomScore,
+        ScoreMode,
+    };
+
+    let mut search = Search::default();
+    search.score_with(function_score(|scoring| {
+        scoring.with(field("in_stock").contains(true));
+        scoring.score_with(field("title").matches("coffee"));
+
+        scoring.function(
+            DecayFunction::gauss("published_at", "30d")
+                .origin("now")
+                .offset("1d")
+                .decay(0.3),
+        );
+        scoring.function(
+            DecayFunction::exp("location", Distance::Kilometers(2))
+                .geo_origin(GeoPoint::new(40.5, -74.0)),
+        );
+        scoring.function(DecayFunction::linear("price", 10).origin(50));
+        scoring.function(
+            FieldValueFactor::new("likes")
+                .factor(1.2)
+                .modifier(Modifier::Log1p)
+                .missing(1.0),
+        );
+        scoring.function(RandomScore::new().seed(42, "_seq_no").weighted(0.5));
+        scoring.weight_when(2.0, |filter| {
+            filter.with(field("featured").contains(true));
+        });
+        scoring.function_when(FieldValueFactor::new("rating"), |filter| {
+            filter.with(!field("rating").exists());
+        });
+
+        scoring.set_score_mode(ScoreMode::Sum);
+        scoring.set_boost_mode(BoostMode::Replace);
+        scoring.set_max_boost(10.0);
+        scoring.set_min_score(0.1);
+        scoring.set_boost(1.5);
+    }));
+
+    assert_eq!(
+        search_to_json(search),
+        json!({
+            "query": {
+                "bool": {
+                    "must": [
+                        {
+                            "function_score": {
+                                "query": {
+                                    "bool": {
+                                        "filter": [ { "term": { "in_stock": true } } ],
+                                        "must": [ { "match": { "title": { "query": "coffee" } } } ]
+                                    }
+                                },
+                                "functions": [
+                                    {
+                                        "gauss": {
+                                            "published_at": {
+                                                "origin": "now",
+                                                "scale": "30d",
+                                                "offset": "1d",
+                                                "decay": 0.3
+                                            }
+                                        }
+                                    },
+                                    {
+                                        "exp": {
+                                            "location": {
+                                                "origin": { "lat": 40.5, "lon": -74.0 },
+                                                "scale": "2km"
+                                            }
+                                        }
+                                    },
+                                    {
+                                        "linear": {
+                                            "price": { "origin": 50, "scale": 10 }
+                                        }
+                                    },
+                                    {
+                                        "field_value_factor": {
+                                            "field": "likes",
+                                            "factor": 1.2,
+                                            "modifier": "log1p",
+                                            "missing": 1.0
+                                        }
+                                    },
+                                    {
+                                        "random_score": { "seed": 42, "field": "_seq_no" },
+                                        "weight": 0.5
+                                    },
+                                    {
+                                        "filter": {
+                                            "bool": {
+                                                "filter": [ { "term": { "featured": true } } ]
+                                            }
+                                        },
+                                        "weight": 2.0
+                                    },
+                                    {
+                                        "field_value_factor": { "field": "rating" },
+                                        "filter": {
+                                            "bool": {
+                                                "must_not": [ { "exists": { "field": "rating" } } ]
+                                            }
+                                        }
+                                    }
+                                ],
+                                "score_mode": "sum",
+                                "boost_mode": "replace",
+                                "max_boost": 10.0,
+                                "min_score": 0.1,
+                                "boost": 1.5
+                            }
+                        }
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn a_function_score_without_a_query_or_functions() {
+    let mut search = Search::default();
+    search.score_with(function_score(|_| {}));
+
+    assert_eq!(
+        search_to_json(search),
+        json!({
+            "query": {
+                "bool": {
+                    "must": [ { "function_score": {} } ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn a_search_with_boosting() {
+    let mut search = Search::default();
+    search.score_with(boosting(0.2, |query| {
+        query.score_with(field("title").matches("apple"));
+        query.demote_with(field("title").matches("pie"));
+        query.demote_with(!field("in_stock").contains(true));
+    }));
+
+    assert_eq!(
+        search_to_json(search),
+        json!({
+            "query": {
+                "bool": {
+                    "must": [
+                        {
+                            "boosting": {
+                                "positive": {
+                                    "bool": {
+                                        "must": [ { "match": { "title": { "query": "apple" } } } ]
+                                    }
+                                },
+                                "negative": {
+                                    "bool": {
+                                        "filter": [ { "match": { "title": { "query": "pie" } } } ],
+                                        "must_not": [ { "term": { "in_stock": true } } ]
+                                    }
+                                },
+                                "negative_boost": 0.2
+                            }
+                        }
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn a_boosting_without_anything_to_demote() {
+    let mut search = Search::default();
+    search.score_with(boosting(0.2, |query| {
+        query.with(field("color").contains("red"));
+    }));
+    search.score_with(boosting(0.2, |_| {}));
+
+    assert_eq!(
+        search_to_json(search),
+        json!({
+            "query": {
+                "bool": {
+                    "must": [
+                        {
+                            "bool": {
+                                "filter": [ { "term": { "color": "red" } } ]
+                            }
+                        }
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn a_boosting_with_only_criteria_to_demote() {
+    let mut search = Search::default();
+    search.score_with(boosting(0.5, |query| {
+        query.demote_with(field("discontinued").contains(true));
+    }));
+
+    assert_eq!(
+        search_to_json(search),
+        json!({
+            "query": {
+                "bool": {
+                    "must": [
+                        {
+                            "boosting": {
+                                "positive": { "match_all": {} },
+                                "negative": {
+                                    "bool": {
+                                        "filter": [ { "term": { "discontinued": true } } ]
+                                    }
+                                },
+                                "negative_boost": 0.5
+                            }
+                        }
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn a_search_with_constant_score() {
+    let mut search = Search::default();
+    search.should_score_with(constant_score(|query| {
+        query.with(field("tags").contains("sale"));
+        query.with(!field("discontinued").exists());
+        query.set_boost(1.5);
+    }));
+    search.should_score_with(constant_score(|_| {}));
+
+    assert_eq!(
+        search_to_json(search),
+        json!({
+            "query": {
+                "bool": {
+                    "should": [
+                        {
+                            "constant_score": {
+                                "filter": {
+                                    "bool": {
+                                        "filter": [ { "term": { "tags": "sale" } } ],
+                                        "must_not": [ { "exists": { "field": "discontinued" } } ]
+                                    }
+                                },
+                                "boost": 1.5
+                            }
+                        }
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn a_search_with_dis_max() {
+    let mut search = Search::default();
+    search.score_with(dis_max(|query| {
+        query.with(field("title").matches("brown fox"));
+        query.with(!field("body").matches("brown fox"));
+        query.set_tie_breaker(0.3);
+    }));
+    search.score_with(dis_max(|_| {}));
+
+    assert_eq!(
+        search_to_json(search),
+        json!({
+            "query": {
+                "bool": {
+                    "must": [
+                        {
+                            "dis_max": {
+                                "queries": [
+                                    { "match": { "title": { "query": "brown fox" } } },
+                                    {
+                                        "bool": {
+                                            "must_not": [
+                                                { "match": { "body": { "query": "brown fox" } } }
+                                            ]
+                                        }
+                                    }
+                                ],
+                                "tie_breaker": 0.3
+                            }
+                        }
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn a_search_with_has_child() {
+    use elastic_lens::request::search::{ChildScoreMode, InnerHits};
+
+    let mut search = Search::default();
+    search.with(has_child("line_item", |child| {
+        child.with(field("sku").contains("ABC-123"));
+        child.score_with(field("description").matches("blue"));
+        child.set_score_mode(ChildScoreMode::Max);
+        child.set_min_children(2);
+        child.set_max_children(10);
+        child.set_inner_hits(InnerHits::new().name("items").size(5));
+    }));
+    search.with(!has_child("return", |_| {}));
+
+    assert_eq!(
+        search_to_json(search),
+        json!({
+            "query": {
+                "bool": {
+                    "filter": [
+                        {
+                            "has_child": {
+                                "type": "line_item",
+                                "query": {
+                                    "bool": {
+                                        "filter": [ { "term": { "sku": "ABC-123" } } ],
+                                        "must": [ { "match": { "description": { "query": "blue" } } } ]
+                                    }
+                                },
+                                "score_mode": "max",
+                                "min_children": 2,
+                                "max_children": 10,
+                                "inner_hits": { "name": "items", "size": 5 }
+                            }
+                        }
+                    ],
+                    "must_not": [
+                        {
+                            "has_child": {
+                                "type": "return",
+                                "query": { "match_all": {} }
+                            }
+                        }
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn a_search_with_has_parent_and_parent_id() {
+    use elastic_lens::request::search::InnerHits;
+
+    let mut search = Search::default();
+    search.with(has_parent("order", |parent| {
+        parent.with(field("status").contains("shipped"));
+        parent.use_parent_score();
+        parent.set_inner_hits(InnerHits::new());
+    }));
+    search.with(!parent_id("line_item", "order-42"));
+
+    assert_eq!(
+        search_to_json(search),
+        json!({
+            "query": {
+                "bool": {
+                    "filter": [
+                        {
+                            "has_parent": {
+                                "parent_type": "order",
+                                "query": {
+                                    "bool": {
+                                        "filter": [ { "term": { "status": "shipped" } } ]
+                                    }
+                                },
+                                "score": true,
+                                "inner_hits": {}
+                            }
+                        }
+                    ],
+                    "must_not": [
+                        { "parent_id": { "type": "line_item", "id": "order-42" } }
+                    ]
+                }
+            }
+        })
+    );
+}
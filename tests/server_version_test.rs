@@ -0,0 +1,58 @@
+#![cfg(all(feature = "official_client", feature = "es_7", feature = "es_8"))]
+
+use elastic_lens::client::{Client, ClientBuilder, DefaultAdapter};
+use elastic_lens::response::ServerVersion;
+use serde_json::Value;
+
+mod common;
+
+use common::record_one_request;
+
+fn client(host: &str, version: &str) -> Client<DefaultAdapter> {
+    ClientBuilder::default()
+        .host(host)
+        .index("inventory")
+        .doc_type("item")
+        .assume_server_version(ServerVersion::new(version))
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn the_doc_type_is_kept_for_elasticsearch_7() {
+    let (host, request) = record_one_request(404, r#"{"found":false}"#).await;
+    let client = client(&host, "7.17.4");
+
+    let doc: Option<Value> = client.get_by_id("42").await.unwrap();
+
+    assert!(doc.is_none());
+    assert_eq!(request.await.unwrap(), "GET /inventory/item/42 HTTP/1.1");
+}
+
+#[tokio::test]
+async fn the_doc_type_is_dropped_for_elasticsearch_8() {
+    let (host, request) = record_one_request(404, r#"{"found":false}"#).await;
+    let client = client(&host, "8.11.0");
+
+    let doc: Option<Value> = client.get_by_id("42").await.unwrap();
+
+    assert!(doc.is_none());
+    assert_eq!(request.await.unwrap(), "GET /inventory/_doc/42 HTTP/1.1");
+}
+
+#[tokio::test]
+async fn an_assumed_version_is_reported_without_asking() {
+    let client = client("http://127.0.0.1:1", "8.11.0");
+
+    assert_eq!(client.server_version().unwrap().major(), Some(8));
+}
+
+#[tokio::test]
+async fn point_in_time_is_refused_before_7_10() {
+    // nothing listens here, so any request would fail differently
+    let client = client("http://127.0.0.1:1", "7.9.3");
+
+    let error = client.open_point_in_time("1m").await.unwrap_err();
+
+    assert!(error.to_string().contains("7.10 or newer"), "{error}");
+}
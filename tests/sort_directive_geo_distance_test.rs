@@ -1,4 +1,4 @@
-use elastic_lens::request::search::{GeoPoint, SortGeo};
+use elastic_lens::request::search::{DistanceUnit, GeoPoint, SortGeo};
 use serde_json::json;
 
 fn to_json<S: serde::Serialize>(value: S) -> serde_json::Value {
@@ -126,3 +126,20 @@ fn geo_distance_with_everything() {
         })
     );
 }
+
+#[test]
+fn geo_distance_sort_measured_in_miles() {
+    let sort = SortGeo::field_and_location("user.loc", GEO_POINT)
+        .measured_in(DistanceUnit::Miles)
+        .build();
+
+    assert_eq!(
+        to_json(sort),
+        json!({
+            "_geo_distance": {
+                "user.loc": { "lat": 1.1, "lon": 2.2 },
+                "unit": "mi"
+            }
+        })
+    );
+}
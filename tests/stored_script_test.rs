@@ -0,0 +1,33 @@
+use elastic_lens::request::{SearchTemplate, StoredScript};
+use serde_json::{json, to_value};
+
+#[test]
+fn stored_script_round_trip() {
+    let script = StoredScript::painless("doc['price'].value * params.factor");
+
+    assert_eq!(
+        to_value(&script).unwrap(),
+        json!({
+            "lang": "painless",
+            "source": "doc['price'].value * params.factor"
+        })
+    );
+
+    let parsed: StoredScript = serde_json::from_value(to_value(&script).unwrap()).unwrap();
+    assert_eq!(parsed, script);
+}
+
+#[test]
+fn search_template_with_params() {
+    let template = SearchTemplate::new("inventory-by-category", [("category", "clothing")]);
+
+    assert_eq!(
+        to_value(template).unwrap(),
+        json!({
+            "id": "inventory-by-category",
+            "params": {
+                "category": "clothing"
+            }
+        })
+    );
+}
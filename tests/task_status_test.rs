@@ -0,0 +1,73 @@
+use elastic_lens::response::TaskStatus;
+use serde_json::json;
+use std::time::Duration;
+
+#[test]
+fn a_running_reindex() {
+    let status: TaskStatus = serde_json::from_value(json!({
+        "completed": false,
+        "task": {
+            "node": "r1A2WoRbTwKZ516z6NEs5A",
+            "id": 36619,
+            "type": "transport",
+            "action": "indices:data/write/reindex",
+            "status": {
+                "total": 6154,
+                "updated": 3500,
+                "created": 0,
+                "deleted": 0,
+                "batches": 4,
+                "version_conflicts": 0,
+                "noops": 0,
+                "retries": { "bulk": 0, "search": 1 },
+                "throttled_millis": 0,
+                "requests_per_second": -1.0,
+                "throttled_until_millis": 0
+            },
+            "description": "reindex from [orders-old] to [orders-new]",
+            "start_time_in_millis": 1535149899665u64,
+            "running_time_in_nanos": 5926916792u64,
+            "cancellable": true,
+            "headers": {}
+        }
+    }))
+    .unwrap();
+
+    assert!(!status.completed);
+    assert_eq!(status.running_time(), Duration::from_nanos(5926916792));
+
+    let progress = status.progress().unwrap();
+    assert_eq!(progress.processed(), 3500);
+    assert_eq!(progress.retries.search, 1);
+    assert!((progress.ratio().unwrap() - 3500.0 / 6154.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn a_failed_task() {
+    let status: TaskStatus = serde_json::from_value(json!({
+        "completed": true,
+        "task": {
+            "node": "r1A2WoRbTwKZ516z6NEs5A",
+            "id": 36620,
+            "type": "transport",
+            "action": "indices:data/write/delete/byquery",
+            "start_time_in_millis": 1535149899665u64,
+            "running_time_in_nanos": 1000,
+            "cancellable": true,
+            "cancelled": true
+        },
+        "error": {
+            "type": "task_cancelled_exception",
+            "reason": "by user request",
+            "caused_by": { "type": "illegal_state_exception" }
+        }
+    }))
+    .unwrap();
+
+    assert!(status.task.cancelled);
+    assert!(status.progress().is_none());
+
+    let error = status.error.unwrap();
+    assert_eq!(error.kind, "task_cancelled_exception");
+    assert_eq!(error.caused_by.unwrap().kind, "illegal_state_exception");
+}
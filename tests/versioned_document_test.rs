@@ -0,0 +1,37 @@
+use elastic_lens::response::{VersionedDocument, WriteResult};
+use serde_json::{json, Value};
+
+#[test]
+fn a_document_with_its_version_details() {
+    let doc: VersionedDocument<Value> = serde_json::from_value(json!({
+        "_index": "inventory",
+        "_id": "42",
+        "_version": 3,
+        "_seq_no": 10,
+        "_primary_term": 1,
+        "found": true,
+        "_source": { "name": "shirt" }
+    }))
+    .unwrap();
+
+    assert_eq!(doc.id, "42");
+    assert_eq!((doc.seq_no, doc.primary_term, doc.version), (10, 1, 3));
+    assert_eq!(doc.doc, json!({ "name": "shirt" }));
+}
+
+#[test]
+fn the_outcome_of_a_write() {
+    let result: WriteResult = serde_json::from_value(json!({
+        "_index": "inventory",
+        "_id": "42",
+        "_version": 4,
+        "result": "updated",
+        "_shards": { "total": 2, "successful": 1, "failed": 0 },
+        "_seq_no": 11,
+        "_primary_term": 1
+    }))
+    .unwrap();
+
+    assert_eq!(result.result, "updated");
+    assert_eq!(result.seq_no, 11);
+}